@@ -0,0 +1,124 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf}
+};
+
+use toml::{Table, Value};
+
+use crate::{Part, Solved};
+
+/// Accepted answers, stored in an `answers.toml` next to the input files.
+///
+/// Each input file has its own table, named after the file without its
+/// extension:
+///
+/// ```toml
+/// [task_1]
+/// part1 = "69289"
+/// part2 = "205615"
+/// ```
+pub struct Answers {
+    path: PathBuf,
+    key: String,
+    table: Table
+}
+
+impl Answers {
+    /// Loads the answers for `input`, which are empty if there is no
+    /// answers file yet.
+    pub fn load(input: &str) -> Result<Answers, String> {
+        let input = Path::new(input);
+        let path = input.with_file_name("answers.toml");
+        let key = input.file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .ok_or(format!("'{}' is not a file", input.display()))?;
+        let table = match fs::read_to_string(&path) {
+            Ok(contents) => contents.parse::<Table>().map_err(|e| format!("{}: {}", path.display(), e))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Table::new(),
+            Err(e) => return Err(format!("could not read '{}': {}", path.display(), e))
+        };
+        Ok(Answers { path, key, table })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        self.table.get(&self.key)?.get(field(part))?.as_str()
+    }
+
+    pub fn set(&mut self, part: Part, answer: &str) {
+        let answers = self.table.entry(self.key.clone()).or_insert(Value::Table(Table::new()));
+        if let Value::Table(answers) = answers {
+            answers.insert(field(part).to_string(), Value::String(answer.to_string()));
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let contents = toml::to_string(&self.table).map_err(|e| e.to_string())?;
+        fs::write(&self.path, contents).map_err(|e| format!("could not write '{}': {}", self.path.display(), e))
+    }
+
+    /// Compares a solved part with the accepted answer, failing parts that
+    /// weren't solved.
+    pub fn verdict(&self, solved: &Solved) -> Verdict {
+        match solved.solved {
+            true => self.check(solved.part, &solved.answer),
+            false => Verdict::Unsolved { reason: solved.answer.clone() }
+        }
+    }
+
+    /// Compares a computed answer with the accepted one.
+    pub fn check(&self, part: Part, answer: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong { expected: expected.to_string(), found: answer.to_string() },
+            None => Verdict::Unknown
+        }
+    }
+}
+
+fn field(part: Part) -> &'static str {
+    match part {
+        Part::One => "part1",
+        Part::Two => "part2"
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String, found: String },
+    Unknown,
+    Unsolved { reason: String }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Unknown => write!(f, "no accepted answer recorded"),
+            Verdict::Unsolved { reason } => write!(f, "not solved, {}", reason),
+            Verdict::Wrong { expected, found } if !expected.contains('\n') && !found.contains('\n') => {
+                write!(f, "wrong, expected {} but found {}", expected, found)
+            },
+            Verdict::Wrong { expected, found } => {
+                write!(f, "wrong, differing lines:")?;
+                let expected: Vec<&str> = expected.lines().collect();
+                let found: Vec<&str> = found.lines().collect();
+                for i in 0..expected.len().max(found.len()) {
+                    let (e, a) = (expected.get(i), found.get(i));
+                    if e != a {
+                        write!(f, "\n  line {}:", i + 1)?;
+                        write!(f, "\n    - {}", e.unwrap_or(&""))?;
+                        write!(f, "\n    + {}", a.unwrap_or(&""))?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::{
+    fmt::{self, Display},
+    hint::black_box,
+    time::{Duration, Instant}
+};
+
+use crate::{parse, ParseError, Part, Solution};
+
+/// A separately measured step of solving a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(Part)
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part(Part::One), Phase::Part(Part::Two)];
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part{}", part)
+        }
+    }
+}
+
+/// Runs `phase` of `S` on `input` `iterations` times and returns the total
+/// time spent in it. The parts run on an input that was parsed beforehand,
+/// so parsing is not included in their time.
+pub fn time<S: Solution>(input: &str, phase: Phase, iterations: u64) -> Result<Duration, ParseError> {
+    let parsed = parse::<S>(input)?;
+    let start = Instant::now();
+    for _ in 0..iterations {
+        match phase {
+            Phase::Parse => drop(black_box(S::parse(black_box(input)))),
+            Phase::Part(Part::One) => drop(black_box(S::part1(black_box(&parsed)))),
+            Phase::Part(Part::Two) => drop(black_box(S::part2(black_box(&parsed))))
+        }
+    }
+    Ok(start.elapsed())
+}
+
+/// Formats `duration` with three significant digits in the largest fitting
+/// unit, e.g. `41.2 µs`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    let (value, unit) = [(1e9, "s"), (1e6, "ms"), (1e3, "µs")].into_iter()
+    .find(|&(scale, _)| nanos >= scale)
+    .map(|(scale, unit)| (nanos / scale, unit))
+    .unwrap_or((nanos, "ns"));
+    let decimals = if value >= 100.0 { 0 } else if value >= 10.0 { 1 } else { 2 };
+    format!("{:.*} {}", decimals, value, unit)
+}
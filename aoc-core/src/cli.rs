@@ -0,0 +1,432 @@
+use std::{
+    borrow::Cow,
+    env,
+    fs::read_to_string,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::exit,
+    str::FromStr,
+    sync::OnceLock
+};
+
+use serde_json::json;
+
+use crate::{answers::{Answers, Verdict}, profile::{self, Span}, solve_parsed, ParseError, Part, Solution, Solved};
+
+/// Where the puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    File(String)
+}
+
+impl Input {
+    pub fn parse(arg: &str) -> Input {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::File(path.to_string())
+        }
+    }
+
+    /// Reads the input. Stdin is only read once, later reads borrow the
+    /// same contents instead of copying them.
+    pub fn read(&self) -> io::Result<Cow<'static, str>> {
+        static STDIN: OnceLock<String> = OnceLock::new();
+        match self {
+            Input::Stdin => {
+                if let Some(input) = STDIN.get() {
+                    return Ok(Cow::Borrowed(input));
+                }
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(Cow::Borrowed(STDIN.get_or_init(|| input)))
+            },
+            Input::File(path) => read_to_string(path).map(Cow::Owned)
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Input::Stdin => "<stdin>",
+            Input::File(path) => path
+        }
+    }
+}
+
+/// How answers are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// One `{day, part, answer, elapsed_ms}` object per line.
+    Json
+}
+
+/// Command line arguments shared by all solvers.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Args {
+    pub input: Option<Input>,
+    pub part: Option<Part>,
+    pub verify: bool,
+    pub record: bool,
+    pub format: Format,
+    pub profile: bool,
+    pub image: Option<String>,
+    /// The day specific options that were given, with their value.
+    pub extra: Vec<(String, Option<String>)>
+}
+
+/// An option only some day understands, e.g. `--top <k>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DayOption {
+    /// The name without the leading `--`.
+    pub name: &'static str,
+    /// The placeholder of the value, or `None` for a flag.
+    pub value: Option<&'static str>,
+    pub help: &'static str
+}
+
+pub const OPTIONS: &str = "    <input>            puzzle input file, `-` reads from stdin
+    --input <input>    same as the positional <input>
+    --part <1|2>       only solve the given part (default: both)
+    --format <format>  print answers as `text` (default) or `json`
+    --verify           compare the answers with the accepted ones in answers.toml
+    --record           store the answers in answers.toml after a correct submission
+    --profile          report the time and peak memory of reading, parsing and each part
+    --image <path>     draw the solved parts as .png or .ppm, on days that can draw them
+    -h, --help         print this message";
+
+impl Args {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Args, String> {
+        Args::parse_with(args, &[])
+    }
+
+    /// Parses the shared arguments and the day specific `options`.
+    pub fn parse_with<I: IntoIterator<Item = String>>(args: I, options: &[DayOption]) -> Result<Args, String> {
+        let mut input = None;
+        let mut part = None;
+        let mut verify = false;
+        let mut record = false;
+        let mut format = Format::Text;
+        let mut profile = false;
+        let mut image = None;
+        let mut extra = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    let value = args.next().ok_or("--part expects a value")?;
+                    part = Some(Part::parse(&value).ok_or(format!("invalid part '{}', expected 1 or 2", value))?);
+                },
+                "--input" => {
+                    let value = args.next().ok_or("--input expects a path or `-`")?;
+                    input = Some(Input::parse(&value));
+                },
+                "--format" => {
+                    format = match args.next().as_deref() {
+                        Some("text") => Format::Text,
+                        Some("json") => Format::Json,
+                        Some(other) => return Err(format!("invalid format '{}', expected text or json", other)),
+                        None => return Err("--format expects a value".to_string())
+                    };
+                },
+                "--image" => image = Some(args.next().ok_or("--image expects a path")?),
+                "--verify" => verify = true,
+                "--record" => record = true,
+                "--profile" => profile = true,
+                flag if flag.starts_with("--") => {
+                    let option = options.iter()
+                    .find(|o| o.name == &flag[2..])
+                    .ok_or(format!("unknown option '{}'", flag))?;
+                    let value = match option.value {
+                        Some(_) => Some(args.next().ok_or(format!("{} expects a value", flag))?),
+                        None => None
+                    };
+                    extra.push((option.name.to_string(), value));
+                },
+                path if input.is_none() => input = Some(Input::parse(path)),
+                other => return Err(format!("unexpected argument '{}'", other))
+            }
+        }
+        if (verify || record) && input == Some(Input::Stdin) {
+            return Err("--verify and --record need an input file".to_string());
+        }
+        if (verify || record) && format == Format::Json {
+            return Err("--verify and --record only work with the text format".to_string());
+        }
+        Ok(Args { input, part, verify, record, format, profile, image, extra })
+    }
+
+    /// Parses the arguments of the running binary, printing the usage and
+    /// exiting on `--help` or invalid arguments.
+    pub fn from_env() -> Args {
+        Args::from_env_with(&[])
+    }
+
+    /// Like [`Args::from_env`], also accepting the day specific `options`.
+    pub fn from_env_with(options: &[DayOption]) -> Args {
+        let args: Vec<String> = env::args().skip(1).collect();
+        if args.iter().any(|a| a == "-h" || a == "--help") {
+            println!("{}", usage(options));
+            exit(0);
+        }
+        match Args::parse_with(args, options) {
+            Ok(args) => args,
+            Err(message) => {
+                eprintln!("error: {}\n\n{}", message, usage(options));
+                exit(2);
+            }
+        }
+    }
+
+    /// Whether the day specific flag `name` was given.
+    pub fn flag(&self, name: &str) -> bool {
+        self.extra.iter().any(|(option, _)| option == name)
+    }
+
+    /// The value of the day specific option `name`, if given. Exits with a
+    /// diagnostic if it is invalid.
+    pub fn option<T: FromStr>(&self, name: &str) -> Option<T> {
+        let value = self.extra.iter().rev().find(|(option, _)| option == name)?.1.as_deref()?;
+        match value.parse() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                eprintln!("error: invalid value '{}' for --{}", value, name);
+                exit(2);
+            }
+        }
+    }
+
+    pub fn wants(&self, part: Part) -> bool {
+        self.part.is_none() || self.part == Some(part)
+    }
+
+    pub fn parts(&self) -> Vec<Part> {
+        Part::ALL.into_iter().filter(|&part| self.wants(part)).collect()
+    }
+
+    /// Where the picture of `part` is saved, if `--image` was given. When
+    /// both parts are solved, the part is added to the file name, e.g.
+    /// `out.png` becomes `out-part1.png`.
+    pub fn image_path(&self, part: Part) -> Option<PathBuf> {
+        let path = Path::new(self.image.as_ref()?);
+        if self.part.is_some() {
+            return Some(path.to_path_buf());
+        }
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let name = match path.extension() {
+            Some(extension) => format!("{}-part{}.{}", stem, part, extension.to_string_lossy()),
+            None => format!("{}-part{}", stem, part)
+        };
+        Some(path.with_file_name(name))
+    }
+
+    /// Checks the `answers` computed for the input file against the accepted
+    /// ones, or records them, if `--verify` or `--record` was given. Returns
+    /// whether none of the answers was wrong.
+    pub fn verify(&self, input: &str, answers: &[Solved]) -> Result<bool, String> {
+        if !self.verify && !self.record {
+            return Ok(true);
+        }
+        let mut accepted = Answers::load(input)?;
+        if let Some(unsolved) = answers.iter().find(|solved| self.record && !solved.solved) {
+            return Err(format!("part {} was not solved and cannot be recorded: {}", unsolved.part, unsolved.answer));
+        }
+        let mut correct = true;
+        for solved in answers {
+            if self.record {
+                accepted.set(solved.part, &solved.answer);
+                println!("part {}: recorded", solved.part);
+            } else {
+                let verdict = accepted.verdict(solved);
+                correct &= !matches!(verdict, Verdict::Wrong { .. } | Verdict::Unsolved { .. });
+                println!("part {}: {}", solved.part, verdict);
+            }
+        }
+        if self.record {
+            accepted.save()?;
+        }
+        Ok(correct)
+    }
+
+    /// Reads and parses the selected input, falling back to `default` if none
+    /// was given. Exits with a diagnostic if the input cannot be read or parsed.
+    pub fn parse_input<S: Solution>(&self, default: &str) -> S::Input {
+        self.parse_input_with(default, S::parse)
+    }
+
+    /// Like [`Args::parse_input`], with a parser other than the solution's.
+    pub fn parse_input_with<T, F>(&self, default: &str, parse: F) -> T
+    where F: FnOnce(&str) -> Result<T, ParseError> {
+        let source = self.input.clone().unwrap_or(Input::File(default.to_string()));
+        let input = match profile::span("read", || source.read()) {
+            Ok(contents) => contents,
+            Err(e) => {
+                eprintln!("error: could not read '{}': {}", source.name(), e);
+                exit(1);
+            }
+        };
+        match profile::span("parse", || parse(&input)) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("error: {}", e.locate(&input).in_file(source.name()));
+                exit(1);
+            }
+        }
+    }
+}
+
+/// Draws a part of a parsed input and saves it at the given path.
+pub type Export<I> = fn(&I, Part, &Path) -> io::Result<()>;
+
+/// Runs `S` as a standalone binary, printing each answer after its label.
+pub fn run<S: Solution>(default: &str, labels: [&str; 2]) {
+    run_with::<S>(&Args::from_env(), default, labels, None);
+}
+
+/// Like [`run`], but also saves a picture of each solved part with `export`
+/// if `--image` is given.
+pub fn run_with_image<S: Solution>(default: &str, labels: [&str; 2], export: Export<S::Input>) {
+    run_with::<S>(&Args::from_env(), default, labels, Some(export));
+}
+
+/// Like [`run`], for days that parse their own options with
+/// [`Args::from_env_with`]. Returns the parsed input for further use.
+pub fn run_with<S: Solution>(args: &Args, default: &str, labels: [&str; 2], export: Option<Export<S::Input>>) -> S::Input {
+    run_with_parser::<S, _>(args, default, labels, export, S::parse)
+}
+
+/// Like [`run_with`], parsing the input with `parse` instead of the
+/// solution's parser, e.g. to take options into account.
+pub fn run_with_parser<S, F>(args: &Args, default: &str, labels: [&str; 2], export: Option<Export<S::Input>>, parse: F) -> S::Input
+where S: Solution, F: FnOnce(&str) -> Result<S::Input, ParseError> {
+    if args.image.is_some() && export.is_none() {
+        eprintln!("error: day {} cannot draw images", S::DAY);
+        exit(2);
+    }
+    let ((input, answers), spans) = profile::record(|| {
+        let input = args.parse_input_with(default, parse);
+        let answers = solve_parsed::<S>(&input, &args.parts());
+        (input, answers)
+    });
+    for solved in &answers {
+        let label = labels[solved.part.number() as usize - 1];
+        match args.format {
+            Format::Json => println!("{}", solved.to_json()),
+            Format::Text if solved.answer.contains('\n') => println!("[Task {}] {}:\n{}", solved.part, label, solved.answer),
+            Format::Text => println!("[Task {}] {}: {}", solved.part, label, solved.answer)
+        }
+    }
+    if args.profile {
+        print_profile(S::DAY, &spans, args.format);
+    }
+    if let Some(export) = export {
+        for part in args.parts() {
+            let Some(path) = args.image_path(part) else { break };
+            if let Err(e) = export(&input, part, &path) {
+                eprintln!("error: could not save '{}': {}", path.display(), e);
+                exit(1);
+            }
+        }
+    }
+    let source = match &args.input {
+        Some(Input::File(path)) => path.as_str(),
+        _ => default
+    };
+    match args.verify(source, &answers) {
+        Ok(true) => {},
+        Ok(false) => exit(1),
+        Err(message) => {
+            eprintln!("error: {}", message);
+            exit(1);
+        }
+    }
+    input
+}
+
+/// Prints the phases recorded while solving `day`, as a table or as one
+/// `{day, profile}` object.
+pub fn print_profile(day: u8, spans: &[Span], format: Format) {
+    match format {
+        Format::Json => println!("{}", json!({ "day": day, "profile": spans.iter().map(Span::to_json).collect::<Vec<_>>() })),
+        Format::Text => {
+            println!("{}", profile::header());
+            for line in profile::report(spans) {
+                println!("{}", line);
+            }
+        }
+    }
+}
+
+fn usage(options: &[DayOption]) -> String {
+    let program = env::args().next()
+    .and_then(|p| Path::new(&p).file_name().map(|n| n.to_string_lossy().into_owned()))
+    .unwrap_or_default();
+    let mut usage = format!("usage: {} [<input>|-] [--part <1|2>] [--format <text|json>] [--verify|--record] [--profile] [--image <path>]", program);
+    for option in options {
+        let flag = format!("--{} {}", option.name, option.value.unwrap_or_default());
+        usage += &format!(" [{}]", flag.trim_end());
+    }
+    usage += &format!("\n\n{}", OPTIONS);
+    for option in options {
+        let flag = format!("--{} {}", option.name, option.value.unwrap_or_default());
+        usage += &format!("\n    {:<19}{}", flag.trim_end(), option.help);
+    }
+    usage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn parses_input_and_part() {
+        let args = parse(&["input.txt", "--part", "2"]).unwrap();
+        assert_eq!(args.input, Some(Input::File("input.txt".to_string())));
+        assert_eq!(args.parts(), vec![Part::Two]);
+        assert_eq!(parse(&["-"]).unwrap().input, Some(Input::Stdin));
+        assert_eq!(parse(&[]).unwrap().parts(), Part::ALL.to_vec());
+        assert_eq!(parse(&["--format", "json"]).unwrap().format, Format::Json);
+        assert!(parse(&["--profile"]).unwrap().profile);
+    }
+
+    #[test]
+    fn parses_day_options() {
+        let options = [
+            DayOption { name: "top", value: Some("<k>"), help: "" },
+            DayOption { name: "stats", value: None, help: "" }
+        ];
+        let args = Args::parse_with(["--stats", "x.txt", "--top", "5"].map(String::from), &options).unwrap();
+        assert_eq!(args.input, Some(Input::File("x.txt".to_string())));
+        assert_eq!(args.option::<usize>("top"), Some(5));
+        assert!(args.flag("stats") && !args.flag("top2"));
+        assert!(Args::parse_with(["--top".to_string()], &options).is_err());
+        assert!(Args::parse_with(["--depth".to_string()], &options).is_err());
+    }
+
+    #[test]
+    fn names_images_per_part() {
+        let args = parse(&["--image", "out/day08.png"]).unwrap();
+        assert_eq!(args.image_path(Part::One), Some(PathBuf::from("out/day08-part1.png")));
+        assert_eq!(args.image_path(Part::Two), Some(PathBuf::from("out/day08-part2.png")));
+        let args = parse(&["--image", "day08.ppm", "--part", "2"]).unwrap();
+        assert_eq!(args.image_path(Part::Two), Some(PathBuf::from("day08.ppm")));
+        assert_eq!(parse(&[]).unwrap().image_path(Part::One), None);
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--part"]).is_err());
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+        assert!(parse(&["--frobnicate"]).is_err());
+        assert!(parse(&["-", "--verify"]).is_err());
+        assert!(parse(&["--format", "xml"]).is_err());
+        assert!(parse(&["--image"]).is_err());
+        assert!(parse(&["--top", "5"]).is_err());
+        assert!(parse(&["--format", "json", "--record"]).is_err());
+    }
+}
@@ -0,0 +1,146 @@
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod parse;
+pub mod profile;
+pub mod random;
+
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant}
+};
+
+use serde_json::Value;
+
+pub use parse::ParseError;
+
+/// A solver for one day of the puzzle.
+///
+/// The input is parsed once and then shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Part1: Answer;
+    type Part2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// The answer to a part, printed as text or as JSON for machine readers.
+///
+/// Displaying the answer gives the text that is submitted and recorded in
+/// `answers.toml`.
+pub trait Answer: Display {
+    fn to_json(&self) -> Value;
+
+    /// Whether the part was solved. An answer that only tells why it wasn't
+    /// is never recorded and fails verification.
+    fn is_solved(&self) -> bool {
+        true
+    }
+}
+
+macro_rules! number_answer {
+    ($($number:ty),*) => {
+        $(impl Answer for $number {
+            fn to_json(&self) -> Value {
+                Value::from(*self)
+            }
+        })*
+    };
+}
+
+number_answer!(i32, i64, u32, u64, usize);
+
+impl Answer for String {
+    fn to_json(&self) -> Value {
+        Value::from(self.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2
+        }
+    }
+
+    pub fn parse(part: &str) -> Option<Part> {
+        match part {
+            "1" => Some(Part::One),
+            "2" => Some(Part::Two),
+            _ => None
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Parses `input`, resolving the position of a parse error within it.
+pub fn parse<S: Solution>(input: &str) -> Result<S::Input, ParseError> {
+    profile::span("parse", || S::parse(input)).map_err(|e| e.locate(input))
+}
+
+/// The answer to one part of a day and how long it took to compute.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Solved {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub json: Value,
+    pub solved: bool,
+    pub elapsed: Duration
+}
+
+impl Solved {
+    /// The answer as `{day, part, answer, elapsed_ms}` object.
+    pub fn to_json(&self) -> Value {
+        serde_json::json!({
+            "day": self.day,
+            "part": self.part.number(),
+            "answer": self.json,
+            "elapsed_ms": self.elapsed.as_secs_f64() * 1000.0
+        })
+    }
+}
+
+fn answer<S: Solution, A: Answer>(part: Part, solver: impl FnOnce() -> A) -> Solved {
+    let (answer, elapsed) = profile::span(&format!("part{}", part), || {
+        let start = Instant::now();
+        let answer = solver();
+        (answer, start.elapsed())
+    });
+    Solved { day: S::DAY, part, answer: answer.to_string(), json: answer.to_json(), solved: answer.is_solved(), elapsed }
+}
+
+/// Solves each of the requested `parts` of an already parsed input.
+pub fn solve_parsed<S: Solution>(input: &S::Input, parts: &[Part]) -> Vec<Solved> {
+    parts.iter()
+    .map(|&part| match part {
+        Part::One => answer::<S, _>(part, || S::part1(input)),
+        Part::Two => answer::<S, _>(part, || S::part2(input))
+    })
+    .collect()
+}
+
+/// Parses `input` once and solves each of the requested `parts`.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<Solved>, ParseError> {
+    let input = parse::<S>(input)?;
+    Ok(solve_parsed::<S>(&input, parts))
+}
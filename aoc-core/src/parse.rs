@@ -0,0 +1,142 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr
+};
+
+/// An error in the puzzle input.
+///
+/// Parsers create errors pointing at the offending token, which has to be a
+/// slice of the input. The line and column are only resolved afterwards by
+/// [`ParseError::locate`], so parsers don't have to keep track of positions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub token: String,
+    pub file: Option<String>,
+    pub location: Option<Location>,
+    address: usize
+}
+
+/// Position of the offending token, both 1-based, and the line it is in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub source: String
+}
+
+impl ParseError {
+    /// Creates an error for `token`, which should be a slice of the input.
+    pub fn at(token: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            token: token.to_string(),
+            file: None,
+            location: None,
+            address: token.as_ptr() as usize
+        }
+    }
+
+    /// Creates an error pointing just behind the end of `text`, e.g. for a
+    /// missing token at the end of a line.
+    pub fn after(text: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at(&text[text.len()..], message)
+    }
+
+    /// Resolves line and column of the token if it is a slice of `input`.
+    pub fn locate(mut self, input: &str) -> ParseError {
+        let start = input.as_ptr() as usize;
+        if self.location.is_some() || self.address < start || self.address > start + input.len() {
+            return self;
+        }
+        let offset = self.address - start;
+        let line_start = input[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = input[offset..].find('\n').map(|i| i + offset).unwrap_or(input.len());
+        self.location = Some(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            source: input[line_start..line_end].trim_end_matches('\r').to_string()
+        });
+        self
+    }
+
+    pub fn in_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut position: Vec<String> = Vec::new();
+        position.extend(self.file.clone());
+        if let Some(location) = &self.location {
+            position.push(location.line.to_string());
+            position.push(location.column.to_string());
+        }
+        if !position.is_empty() {
+            write!(f, "{}: ", position.join(":"))?;
+        }
+        write!(f, "{}", self.message)?;
+        let excerpt = self.token.lines().next().unwrap_or("");
+        if excerpt.is_empty() {
+            write!(f, ", found end of line")?;
+        } else if excerpt.chars().count() > 40 || excerpt.len() < self.token.len() {
+            write!(f, ", found `{}...`", excerpt.chars().take(40).collect::<String>())?;
+        } else {
+            write!(f, ", found `{}`", excerpt)?;
+        }
+        if let Some(Location { line, column, source }) = &self.location {
+            let gutter = " ".repeat(line.to_string().len());
+            let width = excerpt.chars().count().max(1);
+            write!(f, "\n{} |\n{} | {}\n{} | {}{}", gutter, line, source, gutter, " ".repeat(column - 1), "^".repeat(width))?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token` as a number, or any other `FromStr` type.
+pub fn number<T: FromStr>(token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::at(token, "invalid number"))
+}
+
+/// Splits `text` at the first `delimiter`, failing if it doesn't occur.
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+    .ok_or_else(|| ParseError::at(text, format!("expected `{}`", delimiter.escape_default())))
+}
+
+/// Strips `prefix` from `text`, failing if `text` doesn't start with it.
+pub fn prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+    .ok_or_else(|| ParseError::at(text, format!("expected `{}`", prefix.escape_default())))
+}
+
+/// Takes the next token from `tokens`, pointing the error behind `line` if
+/// there is none.
+pub fn next<'a, I: Iterator<Item = &'a str>>(tokens: &mut I, line: &'a str, expected: &str) -> Result<&'a str, ParseError> {
+    tokens.next().ok_or_else(|| ParseError::after(line, format!("expected {}", expected)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_token() {
+        let input = "1-2,3-4\n5-6,x-8";
+        let error = number::<i32>(&input[12..13]).unwrap_err().locate(input);
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (2, 5));
+        assert_eq!(location.source, "5-6,x-8");
+    }
+
+    #[test]
+    fn ignores_foreign_token() {
+        let error = ParseError::at("elsewhere", "invalid").locate("input");
+        assert_eq!(error.location, None);
+    }
+}
@@ -0,0 +1,258 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::RefCell,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant}
+};
+
+use serde_json::{json, Value};
+
+use crate::bench::format_duration;
+
+/// The system allocator, counting the bytes currently allocated and the most
+/// that were allocated at once. Binaries install it with
+/// [`install_profiler!`](crate::install_profiler) to report memory with
+/// `--profile`; elsewhere, such as in tests and benchmarks, spans record no
+/// allocations.
+pub struct Counting;
+
+/// Installs [`Counting`] as the global allocator, once at the top level of
+/// a binary.
+#[macro_export]
+macro_rules! install_profiler {
+    () => {
+        #[global_allocator]
+        static ALLOCATOR: $crate::profile::Counting = $crate::profile::Counting;
+    };
+}
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+fn allocated(bytes: usize) {
+    let now = ALLOCATED.fetch_add(bytes, Ordering::Relaxed) + bytes;
+    PEAK.fetch_max(now, Ordering::Relaxed);
+}
+
+fn freed(bytes: usize) {
+    ALLOCATED.fetch_sub(bytes, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(pointer, layout, new_size);
+        if !new.is_null() {
+            allocated(new_size);
+            freed(layout.size());
+        }
+        new
+    }
+}
+
+/// A measured phase of a solution, with the phases measured within it.
+///
+/// Phases with the same name and parent are merged, e.g. parsing each
+/// monkey of day 11 gives a single `Monkey::parse` phase with one call per
+/// monkey.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub name: String,
+    pub calls: u32,
+    pub elapsed: Duration,
+    /// The most bytes allocated at once during any call, on top of what was
+    /// allocated before it.
+    pub peak_bytes: usize,
+    pub children: Vec<Span>
+}
+
+impl Span {
+    fn new(name: &str) -> Span {
+        Span { name: name.to_string(), calls: 0, elapsed: Duration::ZERO, peak_bytes: 0, children: Vec::new() }
+    }
+
+    /// The span as `{phase, calls, elapsed_ms, peak_bytes, children}` object.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "phase": self.name,
+            "calls": self.calls,
+            "elapsed_ms": self.elapsed.as_secs_f64() * 1000.0,
+            "peak_bytes": self.peak_bytes,
+            "children": self.children.iter().map(Span::to_json).collect::<Vec<_>>()
+        })
+    }
+
+    fn merge(&mut self, other: &Span) {
+        self.calls += other.calls;
+        self.elapsed += other.elapsed;
+        self.peak_bytes = self.peak_bytes.max(other.peak_bytes);
+        merge(&mut self.children, &other.children);
+    }
+}
+
+/// Adds `spans` to `into`, merging spans of the same name.
+pub fn merge(into: &mut Vec<Span>, spans: &[Span]) {
+    for span in spans {
+        match into.iter_mut().find(|s| s.name == span.name) {
+            Some(existing) => existing.merge(span),
+            None => into.push(span.clone())
+        }
+    }
+}
+
+thread_local! {
+    /// The spans recorded so far and the path to the innermost open one, if
+    /// recording.
+    static RECORDING: RefCell<Option<(Span, Vec<usize>)>> = const { RefCell::new(None) };
+}
+
+fn innermost<'a>(root: &'a mut Span, path: &[usize]) -> &'a mut Span {
+    path.iter().fold(root, |span, &i| &mut span.children[i])
+}
+
+/// Runs `f` as a phase called `name` of whatever is being recorded, or just
+/// runs it if nothing is.
+pub fn span<T>(name: &str, f: impl FnOnce() -> T) -> T {
+    let recording = RECORDING.with_borrow_mut(|recording| {
+        let Some((root, path)) = recording else { return false };
+        let parent = innermost(root, path);
+        let index = match parent.children.iter().position(|s| s.name == name) {
+            Some(index) => index,
+            None => {
+                parent.children.push(Span::new(name));
+                parent.children.len() - 1
+            }
+        };
+        path.push(index);
+        true
+    });
+    if !recording {
+        return f();
+    }
+    let base = ALLOCATED.load(Ordering::Relaxed);
+    let outer_peak = PEAK.swap(base, Ordering::Relaxed);
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    let peak = PEAK.fetch_max(outer_peak, Ordering::Relaxed);
+    RECORDING.with_borrow_mut(|recording| {
+        let (root, path) = recording.as_mut().unwrap();
+        let span = innermost(root, path);
+        span.calls += 1;
+        span.elapsed += elapsed;
+        span.peak_bytes = span.peak_bytes.max(peak.saturating_sub(base));
+        path.pop();
+    });
+    result
+}
+
+/// Runs `f`, recording the phases it runs through [`span`].
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Vec<Span>) {
+    RECORDING.set(Some((Span::new(""), Vec::new())));
+    let result = f();
+    let (root, _) = RECORDING.take().unwrap();
+    (result, root.children)
+}
+
+/// Formats a number of bytes with three significant digits in the largest
+/// fitting binary unit, e.g. `1.50 MiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    let (value, unit) = [(1u64 << 30, "GiB"), (1 << 20, "MiB"), (1 << 10, "KiB")].into_iter()
+    .find(|&(scale, _)| bytes as u64 >= scale)
+    .map(|(scale, unit)| (bytes as f64 / scale as f64, unit))
+    .unwrap_or((bytes as f64, "B"));
+    if unit == "B" {
+        return format!("{} B", bytes);
+    }
+    let decimals = if value >= 100.0 { 0 } else if value >= 10.0 { 1 } else { 2 };
+    format!("{:.*} {}", decimals, value, unit)
+}
+
+/// The column titles of [`report`].
+pub fn header() -> String {
+    format!("{:<32} {:>10} {:>10} {:>8}", "Phase", "Time", "Peak", "Calls")
+}
+
+/// One line per span, with nested spans indented below their parent.
+pub fn report(spans: &[Span]) -> Vec<String> {
+    fn lines(spans: &[Span], depth: usize, out: &mut Vec<String>) {
+        for span in spans {
+            let name = format!("{:indent$}{}", "", span.name, indent = 2 * depth);
+            out.push(format!("{:<32} {:>10} {:>10} {:>8}", name, format_duration(span.elapsed), format_bytes(span.peak_bytes), span.calls));
+            lines(&span.children, depth + 1, out);
+        }
+    }
+    let mut out = Vec::new();
+    lines(spans, 0, &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::*;
+
+    crate::install_profiler!();
+
+    #[test]
+    fn records_nested_spans() {
+        let (sum, spans) = record(|| {
+            span("outer", || {
+                let parts: Vec<usize> = (0..3).map(|i| span("inner", || black_box(vec![i as u8; 1 << 16]).len())).collect();
+                parts.iter().sum::<usize>()
+            })
+        });
+        assert_eq!(sum, 3 << 16);
+        assert_eq!(spans.len(), 1);
+        let outer = &spans[0];
+        assert_eq!((outer.name.as_str(), outer.calls), ("outer", 1));
+        assert_eq!((outer.children[0].name.as_str(), outer.children[0].calls), ("inner", 3));
+        assert!(outer.children[0].peak_bytes >= 1 << 16);
+        assert!(outer.elapsed >= outer.children[0].elapsed);
+    }
+
+    #[test]
+    fn runs_spans_without_recording() {
+        assert_eq!(span("alone", || 42), 42);
+    }
+
+    #[test]
+    fn merges_by_name() {
+        let (_, first) = record(|| span("parse", || span("lines", || ())));
+        let (_, second) = record(|| (span("parse", || ()), span("part1", || ())));
+        let mut all = Vec::new();
+        merge(&mut all, &first);
+        merge(&mut all, &second);
+        let names: Vec<(&str, u32)> = all.iter().map(|s| (s.name.as_str(), s.calls)).collect();
+        assert_eq!(names, [("parse", 2), ("part1", 1)]);
+        assert_eq!(all[0].children.len(), 1);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(200 << 20), "200 MiB");
+    }
+}
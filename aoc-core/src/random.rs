@@ -0,0 +1,56 @@
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+pub use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// Writes a valid random puzzle input. `size` scales the input, e.g. the
+/// number of lines or the side of a grid, and small sizes should still
+/// produce solvable inputs.
+pub type Generator = fn(&mut StdRng, usize) -> String;
+
+/// Generates the input for `seed`, which is the same on every run.
+pub fn generate(generator: Generator, seed: u64, size: usize) -> String {
+    generator(&mut StdRng::seed_from_u64(seed), size)
+}
+
+/// Runs `property` on `cases` generated inputs, growing from size 1 up to
+/// `max_size`. When it panics, the seed, size and input of the failing case
+/// are printed so it can be reproduced. `max_size` must be at least 1.
+pub fn check<F: Fn(&str)>(generator: Generator, cases: u64, max_size: usize, property: F) {
+    assert!(max_size > 0, "generated inputs need a maximum size of at least 1");
+    for seed in 0..cases {
+        let size = 1 + (seed as usize * max_size / cases.max(1) as usize).min(max_size - 1);
+        let input = generate(generator, seed, size);
+        if let Err(panic) = catch_unwind(AssertUnwindSafe(|| property(&input))) {
+            eprintln!("property failed for seed {} and size {} on input:\n{}", seed, size, input);
+            resume_unwind(panic);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(rng: &mut StdRng, size: usize) -> String {
+        (0..size).map(|_| rng.gen_range(0..100).to_string() + "\n").collect()
+    }
+
+    #[test]
+    fn generates_reproducibly() {
+        assert_eq!(generate(numbers, 7, 10), generate(numbers, 7, 10));
+        assert_eq!(generate(numbers, 7, 10).lines().count(), 10);
+    }
+
+    #[test]
+    fn checks_growing_sizes() {
+        let sizes = std::cell::RefCell::new(Vec::new());
+        check(numbers, 4, 8, |input| sizes.borrow_mut().push(input.lines().count()));
+        assert_eq!(sizes.into_inner(), [1, 3, 5, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "a maximum size of at least 1")]
+    fn rejects_empty_sizes() {
+        check(numbers, 4, 0, |_| {});
+    }
+}
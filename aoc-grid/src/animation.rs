@@ -0,0 +1,358 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    time::{Duration, Instant}
+};
+
+use aoc_core::{parse, ParseError, Part, Solution};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen}
+};
+
+use crate::{BoundingBox, Grid, Point};
+
+/// The foreground colours of the terminal's basic palette.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Default,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+    BrightRed,
+    BrightGreen,
+    BrightYellow
+}
+
+impl Color {
+    /// The ANSI escape sequence switching to this colour.
+    pub fn code(self) -> &'static str {
+        match self {
+            Color::Default => "\x1b[0m",
+            Color::Red => "\x1b[31m",
+            Color::Green => "\x1b[32m",
+            Color::Yellow => "\x1b[33m",
+            Color::Blue => "\x1b[34m",
+            Color::Magenta => "\x1b[35m",
+            Color::Cyan => "\x1b[36m",
+            Color::White => "\x1b[37m",
+            Color::Grey => "\x1b[90m",
+            Color::BrightRed => "\x1b[91m",
+            Color::BrightGreen => "\x1b[92m",
+            Color::BrightYellow => "\x1b[93m"
+        }
+    }
+}
+
+/// The colour of each character of a frame, characters not listed keep the
+/// default colour.
+pub type Palette = &'static [(char, Color)];
+
+fn color(palette: Palette, c: char) -> Color {
+    palette.iter()
+    .find(|&&(glyph, _)| glyph == c)
+    .map_or(Color::Default, |&(_, color)| color)
+}
+
+/// One step of a simulation, as the cells it changes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    /// The new characters of the cells that changed since the previous
+    /// frame, in the order they are drawn. All are within the grid.
+    pub changes: Vec<(Point, char)>,
+    /// Where the action is, which the viewport keeps in view.
+    pub focus: Point,
+    pub caption: String
+}
+
+impl Frame {
+    pub fn apply(&self, grid: &mut Grid<char>) {
+        for &(position, c) in &self.changes {
+            grid[position] = c;
+        }
+    }
+}
+
+/// The frames of a simulation, computed while they are played, and the
+/// grid they change.
+pub struct Animation {
+    /// The grid before the first frame.
+    pub grid: Grid<char>,
+    pub frames: Box<dyn Iterator<Item = Frame>>,
+    pub palette: Palette
+}
+
+impl Animation {
+    /// Applies every frame, giving the last grid and the last frame.
+    pub fn finish(self) -> (Grid<char>, Option<Frame>) {
+        let Animation { mut grid, frames, .. } = self;
+        let last = frames.inspect(|frame| frame.apply(&mut grid)).last();
+        (grid, last)
+    }
+}
+
+/// A solution whose simulation can be played back.
+pub trait Animate: Solution {
+    fn animate(input: Self::Input, part: Part) -> Animation;
+}
+
+/// Parses `input` and animates the given `part` of it.
+pub fn animate<S: Animate>(input: &str, part: Part) -> Result<Animation, ParseError> {
+    Ok(S::animate(parse::<S>(input)?, part))
+}
+
+/// The part of a frame shown in the terminal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Viewport {
+    pub origin: Point,
+    pub width: usize,
+    pub height: usize
+}
+
+/// The new start of a window of `size` cells along one axis, so that `focus`
+/// stays a quarter of the window away from its ends, and the window stays
+/// within `min..=max` if that is larger than the window.
+fn scroll(start: i32, size: i32, focus: i32, min: i32, max: i32) -> i32 {
+    let margin = size / 4;
+    let start = start
+    .max(focus - (size - 1 - margin))
+    .min(focus - margin);
+    if max - min < size {
+        min
+    } else {
+        start.clamp(min, max - size + 1)
+    }
+}
+
+impl Viewport {
+    pub fn new(width: usize, height: usize) -> Viewport {
+        Viewport { origin: Point::ORIGIN, width, height }
+    }
+
+    pub fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(self.origin, self.origin + Point::new(self.width as i32 - 1, self.height as i32 - 1))
+    }
+
+    /// Scrolls as little as possible to keep `focus` away from the borders,
+    /// without showing more outside of `area` than necessary.
+    pub fn follow(&mut self, focus: Point, area: BoundingBox) {
+        self.origin = Point::new(
+            scroll(self.origin.x, self.width as i32, focus.x, area.min.x, area.max.x),
+            scroll(self.origin.y, self.height as i32, focus.y, area.min.y, area.max.y)
+        );
+    }
+}
+
+/// Renders the part of `grid` within `viewport` with ANSI colours, one line
+/// per row. Positions outside of the grid are left blank.
+pub fn render(grid: &Grid<char>, viewport: &Viewport, palette: Palette) -> Vec<String> {
+    let BoundingBox { min, max } = viewport.bounds();
+    (min.y..=max.y)
+    .map(|y| {
+        let mut line = String::new();
+        let mut current = Color::Default;
+        for x in min.x..=max.x {
+            let c = grid.get(Point::new(x, y)).copied().unwrap_or(' ');
+            let next = color(palette, c);
+            if next != current {
+                line.push_str(next.code());
+                current = next;
+            }
+            line.push(c);
+        }
+        if current != Color::Default {
+            line.push_str(Color::Default.code());
+        }
+        line
+    })
+    .collect()
+}
+
+/// Restores the terminal when playback ends, even by a panic.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, cursor::Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+enum Key {
+    Pause,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+    Other
+}
+
+fn key(event: KeyEvent) -> Key {
+    match event.code {
+        KeyCode::Char('c') if event.modifiers.contains(KeyModifiers::CONTROL) => Key::Quit,
+        KeyCode::Char(' ') => Key::Pause,
+        KeyCode::Right | KeyCode::Char('n') => Key::Step,
+        KeyCode::Char('+') | KeyCode::Up => Key::Faster,
+        KeyCode::Char('-') | KeyCode::Down => Key::Slower,
+        KeyCode::Char('q') | KeyCode::Esc => Key::Quit,
+        _ => Key::Other
+    }
+}
+
+/// Waits up to `timeout` for a key press, or forever without one. Resizing
+/// the terminal counts as another key, so the frame is drawn again.
+fn next_key(timeout: Option<Duration>) -> io::Result<Option<Key>> {
+    if let Some(timeout) = timeout {
+        if !event::poll(timeout)? {
+            return Ok(None);
+        }
+    }
+    Ok(match event::read()? {
+        Event::Key(event) if event.kind != KeyEventKind::Release => Some(key(event)),
+        Event::Resize(..) => Some(Key::Other),
+        _ => None
+    })
+}
+
+/// The fastest rate at which frames are drawn. Faster animations skip
+/// frames instead.
+const MAX_DRAWS_PER_SECOND: f64 = 60.0;
+
+/// Plays animations in the terminal.
+pub struct Player {
+    pub fps: f64
+}
+
+impl Player {
+    pub fn new(fps: f64) -> Player {
+        Player { fps }
+    }
+
+    fn draw(&self, grid: &Grid<char>, frame: &Frame, number: usize, viewport: &mut Viewport, palette: Palette, status: &str) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        viewport.width = width as usize;
+        viewport.height = (height as usize).saturating_sub(2).max(1);
+        viewport.follow(frame.focus, grid.bounds());
+        let mut out = io::stdout().lock();
+        write!(out, "\x1b[H\x1b[2K{}\r\n", frame.caption)?;
+        for line in render(grid, viewport, palette) {
+            write!(out, "\x1b[2K{}\r\n", line)?;
+        }
+        write!(out, "\x1b[2K{}frame {}  {:.0} fps  {}{}", Color::Grey.code(), number, self.fps, status, Color::Default.code())?;
+        out.flush()
+    }
+
+    /// Plays `animation` until its last frame, which stays shown until a key
+    /// is pressed. Space pauses, the right arrow steps one frame, `+` and
+    /// `-` double and halve the frame rate and `q` quits.
+    pub fn play(mut self, animation: Animation) -> io::Result<()> {
+        if !io::stdout().is_terminal() {
+            return Err(io::Error::other("animations need a terminal"));
+        }
+        let Animation { mut grid, mut frames, palette } = animation;
+        let Some(mut frame) = frames.next() else { return Ok(()) };
+        frame.apply(&mut grid);
+        let _screen = Screen::enter()?;
+        let mut viewport = Viewport::new(0, 0);
+        let mut number = 1;
+        let mut paused = false;
+        let mut finished = false;
+        loop {
+            let status = match (finished, paused) {
+                (true, _) => "finished, press any key",
+                (false, true) => "paused  [space] play  [→] step  [q] quit",
+                (false, false) => "[space] pause  [+/-] speed  [q] quit"
+            };
+            self.draw(&grid, &frame, number, &mut viewport, palette, status)?;
+            let delay = Duration::from_secs_f64(1.0 / self.fps.min(MAX_DRAWS_PER_SECOND));
+            let deadline = Instant::now() + delay;
+            let timeout = (!paused && !finished).then(|| deadline.saturating_duration_since(Instant::now()));
+            let advance = match next_key(timeout)? {
+                None if timeout.is_some() => (self.fps / MAX_DRAWS_PER_SECOND).ceil() as usize,
+                Some(_) if finished => return Ok(()),
+                Some(Key::Quit) => return Ok(()),
+                Some(Key::Pause) => {
+                    paused = !paused;
+                    0
+                },
+                Some(Key::Step) => {
+                    paused = true;
+                    1
+                },
+                Some(Key::Faster) => {
+                    self.fps *= 2.0;
+                    0
+                },
+                Some(Key::Slower) => {
+                    self.fps = (self.fps / 2.0).max(0.5);
+                    0
+                },
+                None | Some(Key::Other) => 0
+            };
+            for _ in 0..advance {
+                match frames.next() {
+                    Some(next) => {
+                        next.apply(&mut grid);
+                        frame = next;
+                        number += 1;
+                    },
+                    None => {
+                        finished = true;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn viewport_follows_focus_within_area() {
+        let area = BoundingBox::new(Point::new(-5, 0), Point::new(94, 9));
+        let mut viewport = Viewport::new(20, 20);
+        viewport.follow(Point::new(0, 5), area);
+        assert_eq!(viewport.origin, Point::new(-5, 0));
+        viewport.follow(Point::new(20, 5), area);
+        assert_eq!(viewport.origin, Point::new(6, 0));
+        viewport.follow(Point::new(12, 5), area);
+        assert_eq!(viewport.origin, Point::new(6, 0));
+        viewport.follow(Point::new(94, 5), area);
+        assert_eq!(viewport.origin, Point::new(75, 0));
+    }
+
+    #[test]
+    fn renders_colours_and_blanks() {
+        let grid = Grid::parse("#.\n.o", Ok).unwrap();
+        let palette: Palette = &[('#', Color::Grey), ('o', Color::Yellow)];
+        let viewport = Viewport { origin: Point::new(0, 1), width: 3, height: 2 };
+        assert_eq!(render(&grid, &viewport, palette), [".\x1b[33mo\x1b[0m ", "   "]);
+    }
+
+    #[test]
+    fn applies_changes_in_order() {
+        let frame = |changes: Vec<(Point, char)>| Frame { changes, focus: Point::ORIGIN, caption: String::new() };
+        let frames = vec![frame(vec![(Point::new(1, 0), 'o')]), frame(vec![(Point::new(1, 0), '.'), (Point::new(1, 1), 'o')])];
+        let animation = Animation { grid: Grid::new(2, 2, '.'), frames: Box::new(frames.into_iter()), palette: &[] };
+        let (grid, last) = animation.finish();
+        assert_eq!(grid.to_string(), "..\n.o");
+        assert_eq!(last.unwrap().changes.len(), 2);
+    }
+}
@@ -0,0 +1,152 @@
+use std::{fs, io, path::Path};
+
+use aoc_core::{parse, ParseError, Part, Solution};
+
+use crate::{Grid, Point};
+
+/// A colour as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// A picture of a grid, drawing each cell as a square of `scale` pixels.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+    /// The position of the grid drawn in the top left corner.
+    origin: Point,
+    scale: usize
+}
+
+impl Image {
+    /// Draws every cell of `grid` in the colour given by `color`.
+    pub fn from_grid<T, F: Fn(&T) -> Rgb>(grid: &Grid<T>, scale: usize, color: F) -> Image {
+        let (width, height) = (grid.width() * scale, grid.height() * scale);
+        let mut image = Image { width, height, pixels: vec![[0; 3]; width * height], origin: grid.bounds().min, scale };
+        image.overlay(grid.iter().map(|(position, cell)| (position, color(cell))));
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The colour of the pixel at `(x, y)`, counted from the top left.
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Paints the cells at the given positions of the grid over the picture,
+    /// e.g. to mark a path. Positions outside of the grid are ignored.
+    pub fn overlay<I: IntoIterator<Item = (Point, Rgb)>>(&mut self, cells: I) {
+        for (position, color) in cells {
+            let Point { x, y } = position - self.origin;
+            let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) else { continue };
+            let (x, y) = (x * self.scale, y * self.scale);
+            if x >= self.width || y >= self.height {
+                continue;
+            }
+            for row in y..y + self.scale {
+                self.pixels[row * self.width + x..row * self.width + x + self.scale].fill(color);
+            }
+        }
+    }
+
+    /// The picture as binary PPM.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend(self.pixels.iter().flatten());
+        bytes
+    }
+
+    /// The picture as PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().expect("writing to memory cannot fail");
+        writer.write_image_data(self.pixels.as_flattened()).expect("the image data matches its size");
+        writer.finish().expect("writing to memory cannot fail");
+        bytes
+    }
+
+    /// Writes the picture as PNG or PPM, depending on the extension of `path`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => self.to_png(),
+            Some("ppm") => self.to_ppm(),
+            _ => return Err(io::Error::other("unknown image format, expected .png or .ppm"))
+        };
+        fs::write(path, bytes)
+    }
+}
+
+/// A colour between dark blue for 0, through red, to light yellow for 1.
+pub fn heat(t: f64) -> Rgb {
+    const STOPS: [Rgb; 4] = [[16, 16, 64], [128, 32, 128], [224, 64, 32], [255, 240, 128]];
+    let t = t.clamp(0.0, 1.0) * (STOPS.len() - 1) as f64;
+    let i = (t as usize).min(STOPS.len() - 2);
+    let f = t - i as f64;
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * f).round() as u8;
+    [0, 1, 2].map(|c| mix(STOPS[i][c], STOPS[i + 1][c]))
+}
+
+/// A solution that can draw a picture of each part.
+pub trait Draw: Solution {
+    fn draw(input: &Self::Input, part: Part) -> Image;
+}
+
+/// Parses `input` and draws the given `part` of it.
+pub fn draw<S: Draw>(input: &str, part: Part) -> Result<Image, ParseError> {
+    Ok(S::draw(&parse::<S>(input)?, part))
+}
+
+/// Draws `part` of an already parsed input and saves it at `path`.
+pub fn export<S: Draw>(input: &S::Input, part: Part, path: &Path) -> io::Result<()> {
+    S::draw(input, part).save(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BoundingBox;
+
+    #[test]
+    fn draws_scaled_cells_and_overlay() {
+        let mut grid = Grid::with_bounds(BoundingBox::new(Point::new(5, 5), Point::new(6, 5)), false);
+        grid[Point::new(6, 5)] = true;
+        let mut image = Image::from_grid(&grid, 2, |&on| if on { [255; 3] } else { [0; 3] });
+        image.overlay([(Point::new(5, 5), [255, 0, 0]), (Point::new(9, 9), [0, 255, 0])]);
+        assert_eq!((image.width(), image.height()), (4, 2));
+        assert_eq!(image.pixel(1, 1), [255, 0, 0]);
+        assert_eq!(image.pixel(2, 0), [255; 3]);
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n4 2\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 2 * 3);
+    }
+
+    #[test]
+    fn encodes_png() {
+        let image = Image::from_grid(&Grid::new(3, 2, 0), 1, |_| [1, 2, 3]);
+        let png = image.to_png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        let decoder = png::Decoder::new(png.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (3, 2));
+        assert_eq!(&pixels[..3], [1, 2, 3]);
+    }
+
+    #[test]
+    fn heat_runs_from_blue_to_yellow() {
+        assert_eq!(heat(0.0), [16, 16, 64]);
+        assert_eq!(heat(1.0), [255, 240, 128]);
+        assert_eq!(heat(2.0), heat(1.0));
+    }
+}
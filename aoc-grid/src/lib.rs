@@ -0,0 +1,241 @@
+pub mod animation;
+mod geometry;
+pub mod image;
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut}
+};
+
+use aoc_core::{Answer, ParseError};
+use serde_json::Value;
+
+pub use geometry::{BoundingBox, Direction, Point, STEPS_4, STEPS_8};
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// The top left cell doesn't have to be at `(0, 0)`: a grid covers any
+/// rectangle of positions and can grow to include new ones.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    origin: Point
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a `width` x `height` grid starting at `(0, 0)`.
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid { cells: vec![fill; width * height], width, height, origin: Point::ORIGIN }
+    }
+
+    /// Creates a grid covering all positions within `bounds`.
+    pub fn with_bounds(bounds: BoundingBox, fill: T) -> Grid<T> {
+        let (width, height) = (bounds.width(), bounds.height());
+        Grid { cells: vec![fill; width * height], width, height, origin: bounds.min }
+    }
+
+    /// Extends the bounds to include `position`, filling new cells with `fill`.
+    /// Each extension copies the whole grid, so grow to the furthest position
+    /// once rather than cell by cell.
+    pub fn grow_to(&mut self, position: Point, fill: T) {
+        if self.contains(position) {
+            return;
+        }
+        let mut bounds = BoundingBox::new(position, position);
+        if !self.cells.is_empty() {
+            bounds.extend(self.bounds().min);
+            bounds.extend(self.bounds().max);
+        }
+        let mut grown = Grid::with_bounds(bounds, fill);
+        let positions: Vec<Point> = self.positions().collect();
+        for (position, cell) in positions.into_iter().zip(self.cells.drain(..)) {
+            grown[position] = cell;
+        }
+        *self = grown;
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a block of characters, one row per line, converting each
+    /// character with `cell`. All rows must have the same length.
+    pub fn parse<F>(input: &str, mut cell: F) -> Result<Grid<T>, ParseError>
+    where F: FnMut(char) -> Result<T, String> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines() {
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() || *width.get_or_insert(line.chars().count()) != line.chars().count() {
+                return Err(ParseError::at(line, "all rows of the grid must have the same, non-zero length"));
+            }
+            for (i, c) in line.char_indices() {
+                cells.push(cell(c).map_err(|message| ParseError::at(&line[i..i + c.len_utf8()], message))?);
+            }
+            height += 1;
+        }
+        let width = width.ok_or_else(|| ParseError::at(input, "expected a grid of characters"))?;
+        Ok(Grid { cells, width, height, origin: Point::ORIGIN })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The positions covered by the grid, from the top left to the bottom
+    /// right.
+    pub fn bounds(&self) -> BoundingBox {
+        let size = Point::new(self.width as i32, self.height as i32);
+        BoundingBox::new(self.origin, self.origin + size - Point::new(1, 1))
+    }
+
+    pub fn contains(&self, position: Point) -> bool {
+        self.bounds().contains(position)
+    }
+
+    fn offset(&self, position: Point) -> Option<usize> {
+        self.contains(position).then(|| {
+            let Point { x, y } = position - self.origin;
+            y as usize * self.width + x as usize
+        })
+    }
+
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.offset(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        self.offset(position).map(|i| &mut self.cells[i])
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        self.bounds().points()
+    }
+
+    /// All cells with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The cells of row `y`, from left to right.
+    pub fn row(&self, y: i32) -> impl Iterator<Item = &T> {
+        let BoundingBox { min, max } = self.bounds();
+        (min.x..=max.x).filter_map(move |x| self.get(Point::new(x, y)))
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        let BoundingBox { min, max } = self.bounds();
+        (min.y..=max.y).filter_map(move |y| self.get(Point::new(x, y)))
+    }
+
+    /// The cells reached by repeatedly moving by `step` from `start`, up to
+    /// the border of the grid. `start` itself is not included.
+    pub fn ray(&self, start: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        (1..)
+        .map(move |i| start + step * i)
+        .map_while(move |position| self.get(position).map(|cell| (position, cell)))
+    }
+
+    /// The positions next to `position` within the grid, in the order of
+    /// `steps`, e.g. [`STEPS_4`] or [`STEPS_8`].
+    pub fn neighbours<'a>(&'a self, position: Point, steps: &'a [Point]) -> impl Iterator<Item = Point> + 'a {
+        steps.iter()
+        .map(move |&step| position + step)
+        .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    pub fn neighbours_4(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(position, &STEPS_4)
+    }
+
+    pub fn neighbours_8(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(position, &STEPS_8)
+    }
+
+    /// Converts every cell, keeping the positions.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height, origin: self.origin }
+    }
+
+    /// Renders one line per row, drawing each cell with `glyph`.
+    pub fn render<F: Fn(&T) -> char>(&self, glyph: F) -> String {
+        let rows: Vec<String> = self.cells.chunks(self.width.max(1))
+        .map(|row| row.iter().map(&glyph).collect())
+        .collect();
+        rows.join("\n")
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &T {
+        let bounds = self.bounds();
+        self.get(position)
+        .unwrap_or_else(|| panic!("position {:?} outside of the grid {:?}", position, bounds))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut T {
+        let bounds = self.bounds();
+        self.get_mut(position)
+        .unwrap_or_else(|| panic!("position {:?} outside of the grid {:?}", position, bounds))
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(|&c| c))
+    }
+}
+
+/// Pictures like day 10's screen are answered with the letters they show,
+/// but as JSON they are given as a list of rows.
+impl Answer for Grid<char> {
+    fn to_json(&self) -> Value {
+        Value::from(self.to_string().lines().collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_renders() {
+        let grid = Grid::parse("ab\ncd", Ok).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[Point::new(1, 0)], 'b');
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.to_string(), "ab\ncd");
+        assert!(Grid::parse("ab\nc", Ok).is_err());
+        assert!(Grid::parse("12\n3x", |c| c.to_digit(10).ok_or("not a digit".to_string())).is_err());
+    }
+
+    #[test]
+    fn iterates_rows_columns_and_rays() {
+        let grid = Grid::parse("123\n456\n789", |c| Ok(c.to_digit(10).unwrap())).unwrap();
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6, 9]);
+        assert_eq!(grid.ray(Point::ORIGIN, Point::new(1, 1)).map(|(_, &c)| c).collect::<Vec<_>>(), [5, 9]);
+        assert_eq!(grid.neighbours_4(Point::ORIGIN).collect::<Vec<_>>(), [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours_8(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn grows_to_include_positions() {
+        let mut grid = Grid::with_bounds(BoundingBox::new(Point::new(3, 1), Point::new(4, 1)), '.');
+        grid[Point::new(4, 1)] = '#';
+        grid.grow_to(Point::new(1, 2), '.');
+        assert_eq!(grid.bounds(), BoundingBox::new(Point::new(1, 1), Point::new(4, 2)));
+        assert_eq!(grid.to_string(), "...#\n....");
+    }
+}
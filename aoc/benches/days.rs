@@ -0,0 +1,25 @@
+use std::fs::read_to_string;
+
+use aoc::days::DAYS;
+use aoc_core::bench::Phase;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Benchmarks parsing and both parts of every day as `dayNN/<phase>`.
+/// Select days or phases with a filter, e.g. `cargo bench -p aoc -- day12`.
+fn days(c: &mut Criterion) {
+    for day in DAYS {
+        let input = read_to_string(day.input).unwrap();
+        let mut group = c.benchmark_group(format!("day{:02}", day.number));
+        // The minimum number of samples, some parts take seconds per run
+        group.sample_size(10);
+        for phase in Phase::ALL {
+            group.bench_function(phase.to_string(), |b| {
+                b.iter_custom(|iterations| (day.timer)(&input, phase, iterations).unwrap())
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
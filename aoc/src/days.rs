@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use aoc_core::{bench::{time, Phase}, random::Generator, solve, ParseError, Part, Solution, Solved};
+use aoc_grid::{animation::{animate, Animation}, image::{draw, Image}};
+
+pub type Solver = fn(&str, &[Part]) -> Result<Vec<Solved>, ParseError>;
+pub type Timer = fn(&str, Phase, u64) -> Result<Duration, ParseError>;
+pub type Animator = fn(&str, Part) -> Result<Animation, ParseError>;
+pub type Drawer = fn(&str, Part) -> Result<Image, ParseError>;
+
+pub struct Day {
+    pub number: u8,
+    pub name: &'static str,
+    pub input: &'static str,
+    pub solver: Solver,
+    pub timer: Timer,
+    pub generator: Generator
+}
+
+macro_rules! day {
+    ($krate:ident, $solution:ident) => {
+        Day {
+            number: <$krate::$solution as Solution>::DAY,
+            name: stringify!($krate),
+            input: concat!(env!("CARGO_MANIFEST_DIR"), "/../", stringify!($krate), "/input/task_1.txt"),
+            solver: solve::<$krate::$solution>,
+            timer: time::<$krate::$solution>,
+            generator: $krate::generate::generate
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(day01_calorie_counting, Day01),
+    day!(day02_rock_paper_scissors, Day02),
+    day!(day03_rucksack_reorganization, Day03),
+    day!(day04_camp_cleanup, Day04),
+    day!(day05_supply_stacks, Day05),
+    day!(day06_tuning_trouble, Day06),
+    day!(day07_no_space_left_on_device, Day07),
+    day!(day08_treetop_tree_house, Day08),
+    day!(day09_rope_bridge, Day09),
+    day!(day10_cathode_ray_tube, Day10),
+    day!(day11_monkey_in_the_middle, Day11),
+    day!(day12_hill_climbing_algorithm, Day12),
+    day!(day13_distress_signal, Day13),
+    day!(day14_regolith_reservoir, Day14),
+    day!(day16_proboscidea_volcanium, Day16),
+    day!(day20_grove_positioning_system, Day20),
+    day!(day21_monkey_math, Day21),
+];
+
+/// The days whose simulation can be played back by `aoc animate`.
+pub const ANIMATIONS: &[(u8, Animator)] = &[
+    (9, animate::<day09_rope_bridge::Day09>),
+    (10, animate::<day10_cathode_ray_tube::Day10>),
+    (14, animate::<day14_regolith_reservoir::Day14>),
+];
+
+/// The days that can draw pictures with `--image`.
+pub const DRAWINGS: &[(u8, Drawer)] = &[
+    (8, draw::<day08_treetop_tree_house::Day08>),
+    (10, draw::<day10_cathode_ray_tube::Day10>),
+    (12, draw::<day12_hill_climbing_algorithm::Day12>),
+    (14, draw::<day14_regolith_reservoir::Day14>),
+];
+
+pub const LAST_DAY: u8 = 25;
+
+pub fn animator(number: u8) -> Option<Animator> {
+    ANIMATIONS.iter().find(|&&(day, _)| day == number).map(|&(_, animator)| animator)
+}
+
+pub fn drawer(number: u8) -> Option<Drawer> {
+    DRAWINGS.iter().find(|&&(day, _)| day == number).map(|&(_, drawer)| drawer)
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,324 @@
+use std::{env, process::exit, time::Duration};
+
+use aoc::days::{self, Animator, Day, DAYS, LAST_DAY};
+use aoc_core::{
+    answers::{Answers, Verdict},
+    bench::{format_duration, Phase},
+    cli::{self, Args, Format, Input},
+    profile::{self, Span},
+    random,
+    Part,
+    Solved
+};
+use aoc_grid::animation::Player;
+
+aoc_core::install_profiler!();
+
+const USAGE: &str = "\
+usage: aoc <command>
+
+commands:
+    run <day> [<input>|-] [options]         solve one or both parts of a day
+    verify [<day>...]                       check all or the given days against their accepted answers
+    bench [<day>...]                        time parsing and both parts of all or the given days
+    profile [<day>...]                      report time and peak memory per phase of all or the given days
+    generate <day> [<size>] [<seed>]        print a random input for a day, 10 and 0 by default
+    animate <day> [<input>|-] [--part <1|2>] [--fps <n>]
+                                            play the simulation of day 9, 10 or 14 in the terminal
+    list                                    show which days and parts are solved
+
+options of run:";
+
+struct RunArgs {
+    day: u8,
+    args: Args
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let (day, rest) = args.split_first().ok_or("missing day")?;
+    let day: u8 = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+    if !(1..=LAST_DAY).contains(&day) {
+        return Err(format!("day must be between 1 and {}", LAST_DAY));
+    }
+    let args = Args::parse(rest.iter().cloned())?;
+    Ok(RunArgs { day, args })
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("error: {}\n\n{}\n{}", message, USAGE, cli::OPTIONS);
+    exit(2);
+}
+
+fn print_answer(solved: &Solved, format: Format) {
+    match format {
+        Format::Json => println!("{}", solved.to_json()),
+        Format::Text if solved.answer.contains('\n') => println!("Day {:02}, part {}:\n{}", solved.day, solved.part, solved.answer),
+        Format::Text => println!("Day {:02}, part {}: {}", solved.day, solved.part, solved.answer)
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let day = days::find(args.day).ok_or(format!("day {} is not solved yet", args.day))?;
+    let drawer = match args.args.image {
+        Some(_) => Some(days::drawer(day.number).ok_or(format!("day {} cannot draw images", day.number))?),
+        None => None
+    };
+    let source = args.args.input.clone().unwrap_or(Input::File(day.input.to_string()));
+    let (solved, spans) = profile::record(|| {
+        let input = profile::span("read", || source.read()).map_err(|e| format!("could not read '{}': {}", source.name(), e))?;
+        let answers = (day.solver)(&input, &args.args.parts()).map_err(|e| e.in_file(source.name()).to_string())?;
+        Ok::<_, String>((input, answers))
+    });
+    let (input, answers) = solved?;
+    for solved in &answers {
+        print_answer(solved, args.args.format);
+    }
+    if args.args.profile {
+        cli::print_profile(day.number, &spans, args.args.format);
+    }
+    if let Some(drawer) = drawer {
+        for part in args.args.parts() {
+            let path = args.args.image_path(part).unwrap();
+            let image = drawer(&input, part).map_err(|e| e.in_file(source.name()).to_string())?;
+            image.save(&path).map_err(|e| format!("could not save '{}': {}", path.display(), e))?;
+        }
+    }
+    if !args.args.verify(source.name(), &answers)? {
+        exit(1);
+    }
+    Ok(())
+}
+
+fn parse_days(args: &[String]) -> Result<Vec<&'static Day>, String> {
+    if args.is_empty() {
+        return Ok(DAYS.iter().collect());
+    }
+    args.iter()
+    .map(|arg| {
+        let number: u8 = arg.parse().map_err(|_| format!("invalid day '{}'", arg))?;
+        days::find(number).ok_or(format!("day {} is not solved yet", number))
+    })
+    .collect()
+}
+
+/// Solves every part of `days` with their default input and compares the
+/// answers with the accepted ones.
+fn verify(days: &[&Day]) -> Result<(), String> {
+    let mut wrong = 0;
+    let mut unknown = 0;
+    for day in days {
+        let input = Input::File(day.input.to_string());
+        let contents = input.read().map_err(|e| format!("could not read '{}': {}", input.name(), e))?;
+        let accepted = Answers::load(day.input)?;
+        let answers = (day.solver)(&contents, &Part::ALL).map_err(|e| e.in_file(input.name()).to_string())?;
+        for solved in answers {
+            let verdict = accepted.verdict(&solved);
+            match verdict {
+                Verdict::Correct => {},
+                Verdict::Wrong { .. } | Verdict::Unsolved { .. } => wrong += 1,
+                Verdict::Unknown => unknown += 1
+            }
+            println!("Day {:02}, part {}: {}", day.number, solved.part, verdict);
+        }
+    }
+    let total = 2 * days.len();
+    println!("{} of {} answers correct, {} wrong, {} unknown", total - wrong - unknown, total, wrong, unknown);
+    if wrong > 0 {
+        exit(1);
+    }
+    Ok(())
+}
+
+/// Wall clock time to spend on one sample of a phase.
+const SAMPLE_TIME: Duration = Duration::from_millis(100);
+const SAMPLES: usize = 5;
+
+/// Measures the time of one run of `phase`, as the median over a few samples
+/// of repeated runs. Phases slower than a sample are only run once.
+fn measure(day: &Day, input: &str, phase: Phase) -> Result<Duration, String> {
+    let timer = |iterations| (day.timer)(input, phase, iterations).map_err(|e| e.in_file(day.input).to_string());
+    let once = timer(1)?;
+    if once >= SAMPLE_TIME {
+        return Ok(once);
+    }
+    let iterations = (SAMPLE_TIME.as_nanos() / once.as_nanos().max(1)).min(1_000_000) as u32;
+    let mut samples = (0..SAMPLES)
+    .map(|_| timer(iterations as u64).map(|total| total / iterations))
+    .collect::<Result<Vec<_>, _>>()?;
+    samples.sort();
+    Ok(samples[SAMPLES / 2])
+}
+
+/// Times every phase of `days` with their default input and prints a table.
+fn bench(days: &[&Day]) -> Result<(), String> {
+    println!("{:<3}  {:<34} {:>10} {:>10} {:>10} {:>10}", "Day", "Name", "Parse", "Part 1", "Part 2", "Total");
+    let mut total = Duration::ZERO;
+    for day in days {
+        let source = Input::File(day.input.to_string());
+        let input = source.read().map_err(|e| format!("could not read '{}': {}", source.name(), e))?;
+        let times = Phase::ALL.iter()
+        .map(|&phase| measure(day, &input, phase))
+        .collect::<Result<Vec<_>, _>>()?;
+        let sum: Duration = times.iter().sum();
+        total += sum;
+        println!("{:>3}  {:<34} {:>10} {:>10} {:>10} {:>10}",
+            format!("{:02}", day.number),
+            day.name,
+            format_duration(times[0]),
+            format_duration(times[1]),
+            format_duration(times[2]),
+            format_duration(sum)
+        );
+    }
+    println!("{:<3}  {:<34} {:>43}", "", "all days", format_duration(total));
+    Ok(())
+}
+
+fn parse_generate_args(args: &[String]) -> Result<(&'static Day, usize, u64), String> {
+    let days = parse_days(&args[..args.len().min(1)])?;
+    if days.len() != 1 {
+        return Err("missing day".to_string());
+    }
+    let size = match args.get(1) {
+        Some(size) => size.parse().ok().filter(|&s| s > 0).ok_or(format!("invalid size '{}'", size))?,
+        None => 10
+    };
+    let seed = match args.get(2) {
+        Some(seed) => seed.parse().map_err(|_| format!("invalid seed '{}'", seed))?,
+        None => 0
+    };
+    if args.len() > 3 {
+        return Err(format!("unexpected argument '{}'", args[3]));
+    }
+    Ok((days[0], size, seed))
+}
+
+struct AnimateArgs {
+    day: &'static Day,
+    animator: Animator,
+    input: Option<Input>,
+    part: Part,
+    fps: f64
+}
+
+fn parse_animate_args(args: &[String]) -> Result<AnimateArgs, String> {
+    let days = parse_days(&args[..args.len().min(1)])?;
+    let day = *days.first().ok_or("missing day")?;
+    let animator = days::animator(day.number).ok_or(format!("day {} has no animation", day.number))?;
+    let mut input = None;
+    let mut part = Part::One;
+    let mut fps = 30.0;
+    let mut args = args[1..].iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = args.next().ok_or("--part expects a value")?;
+                part = Part::parse(value).ok_or(format!("invalid part '{}', expected 1 or 2", value))?;
+            },
+            "--fps" => {
+                let value = args.next().ok_or("--fps expects a value")?;
+                fps = value.parse().ok().filter(|&fps: &f64| fps > 0.0).ok_or(format!("invalid frame rate '{}'", value))?;
+            },
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            path if input.is_none() => input = Some(Input::parse(path)),
+            other => return Err(format!("unexpected argument '{}'", other))
+        }
+    }
+    Ok(AnimateArgs { day, animator, input, part, fps })
+}
+
+fn animate(args: AnimateArgs) -> Result<(), String> {
+    let source = args.input.unwrap_or(Input::File(args.day.input.to_string()));
+    let input = source.read().map_err(|e| format!("could not read '{}': {}", source.name(), e))?;
+    let animation = (args.animator)(&input, args.part).map_err(|e| e.in_file(source.name()).to_string())?;
+    Player::new(args.fps).play(animation).map_err(|e| e.to_string())
+}
+
+/// Solves `days` with their default input while recording their phases, and
+/// prints them followed by the phases of all days combined.
+fn profile(days: &[&Day]) -> Result<(), String> {
+    println!("Day  {}", profile::header());
+    let mut combined: Vec<Span> = Vec::new();
+    for day in days {
+        let source = Input::File(day.input.to_string());
+        let (solved, spans) = profile::record(|| {
+            let input = profile::span("read", || source.read()).map_err(|e| format!("could not read '{}': {}", source.name(), e))?;
+            (day.solver)(&input, &Part::ALL).map_err(|e| e.in_file(source.name()).to_string())
+        });
+        solved?;
+        for (i, line) in profile::report(&spans).iter().enumerate() {
+            let number = if i == 0 { format!("{:02}", day.number) } else { String::new() };
+            println!("{:>3}  {}", number, line);
+        }
+        profile::merge(&mut combined, &spans);
+    }
+    let phases: Vec<Span> = combined.into_iter()
+    .map(|span| Span { children: Vec::new(), ..span })
+    .collect();
+    for (i, line) in profile::report(&phases).iter().enumerate() {
+        println!("{:>3}  {}", if i == 0 { "all" } else { "" }, line);
+    }
+    Ok(())
+}
+
+fn list() {
+    for number in 1..=LAST_DAY {
+        match days::find(number) {
+            Some(day) => println!("Day {:02}: parts 1, 2 ({})", number, day.name),
+            None if number == 19 => println!("Day {:02}: missing (Python solution in day19_not_enough_minerals)", number),
+            None => println!("Day {:02}: missing", number)
+        }
+    }
+    let missing: Vec<String> = (1..=LAST_DAY)
+        .filter(|&n| days::find(n).is_none())
+        .map(|n| n.to_string())
+        .collect();
+    println!("{} of {} days solved, missing: {}", DAYS.len(), LAST_DAY, missing.join(", "));
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|a| a == "-h" || a == "--help") {
+        println!("{}\n{}", USAGE, cli::OPTIONS);
+        return;
+    }
+    let result = match args.first().map(String::as_str) {
+        Some("run") => match parse_run_args(&args[1..]) {
+            Ok(run_args) => run(run_args),
+            Err(message) => usage_error(&message)
+        },
+        Some("verify") => match parse_days(&args[1..]) {
+            Ok(days) => verify(&days),
+            Err(message) => usage_error(&message)
+        },
+        Some("bench") => match parse_days(&args[1..]) {
+            Ok(days) => bench(&days),
+            Err(message) => usage_error(&message)
+        },
+        Some("profile") => match parse_days(&args[1..]) {
+            Ok(days) => profile(&days),
+            Err(message) => usage_error(&message)
+        },
+        Some("generate") => match parse_generate_args(&args[1..]) {
+            Ok((day, size, seed)) => {
+                print!("{}", random::generate(day.generator, seed, size));
+                Ok(())
+            },
+            Err(message) => usage_error(&message)
+        },
+        Some("animate") => match parse_animate_args(&args[1..]) {
+            Ok(animate_args) => animate(animate_args),
+            Err(message) => usage_error(&message)
+        },
+        Some("list") => {
+            list();
+            Ok(())
+        },
+        Some(command) => usage_error(&format!("unknown command '{}'", command)),
+        None => usage_error("missing command")
+    };
+    if let Err(message) = result {
+        eprintln!("error: {}", message);
+        exit(1);
+    }
+}
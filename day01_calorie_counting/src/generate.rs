@@ -0,0 +1,63 @@
+use aoc_core::random::{Rng, StdRng};
+
+/// `size` elves carrying one to five items each. Some elves are followed by
+/// more than one blank line. Some inputs end lines with CRLF or lack the
+/// blank line after the last elf.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let newline = if rng.gen_bool(0.2) { "\r\n" } else { "\n" };
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..rng.gen_range(1..=5) {
+            input += &format!("{}{}", rng.gen_range(1..=10000), newline);
+        }
+        input += newline;
+        if rng.gen_bool(0.1) {
+            input += newline;
+        }
+    }
+    if !input.is_empty() && rng.gen_bool(0.3) {
+        input.truncate(input.len() - newline.len());
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day01;
+
+    /// The calories of each elf, in the order of the input.
+    fn reference_totals(input: &str) -> Vec<i32> {
+        let mut input = input.replace("\r\n", "\n");
+        if input.lines().last().is_some_and(|line| !line.is_empty()) {
+            input.push('\n');
+        }
+        let lines: Vec<&str> = input.lines().collect();
+        lines.split(|line| line.is_empty())
+        .filter(|group| !group.is_empty())
+        .map(|group| group.iter().map(|line| line.parse::<i32>().unwrap()).sum())
+        .collect()
+    }
+
+    fn reference_top(mut totals: Vec<i32>, n: usize) -> i32 {
+        let mut sum = 0;
+        for _ in 0..n.min(totals.len()) {
+            let (i, &max) = totals.iter().enumerate().max_by_key(|&(_, t)| t).unwrap();
+            sum += max;
+            totals.remove(i);
+        }
+        sum
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 200, 20, |input| {
+            let backpacks = Day01::parse(input).unwrap();
+            let totals = reference_totals(input);
+            assert_eq!(Day01::part1(&backpacks), reference_top(totals.clone(), 1));
+            assert_eq!(Day01::part2(&backpacks), reference_top(totals, 3));
+        });
+    }
+}
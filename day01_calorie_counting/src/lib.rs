@@ -0,0 +1,216 @@
+pub mod generate;
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    mem
+};
+
+use aoc_core::{parse::number, ParseError, Solution};
+use serde_json::{json, Value};
+
+/// An elf and the snacks it carries.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Elf {
+    /// The position of the elf in the input, starting at 1.
+    pub index: usize,
+    pub calories: i32,
+    /// The calories of each snack, in the order of the input.
+    pub snacks: Vec<i32>
+}
+
+impl Elf {
+    pub fn to_json(&self) -> Value {
+        json!({ "elf": self.index, "calories": self.calories, "snacks": self.snacks })
+    }
+}
+
+/// The elves carrying the most calories, and statistics over all of them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Inventory {
+    /// At most `k` elves, most calories first. Elves with the same calories
+    /// are ordered by their position.
+    pub top: Vec<Elf>,
+    pub stats: Stats
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub elves: usize,
+    pub items: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub mean_items: f64
+}
+
+impl Stats {
+    fn of(calories: &mut [i32], items: usize) -> Stats {
+        let elves = calories.len();
+        let mean = calories.iter().map(|&c| c as f64).sum::<f64>() / elves as f64;
+        let middle = elves / 2;
+        let (lower, &mut upper, _) = calories.select_nth_unstable(middle);
+        let median = if elves % 2 == 1 {
+            upper as f64
+        } else {
+            (*lower.iter().max().unwrap() as f64 + upper as f64) / 2.0
+        };
+        Stats { elves, items, mean, median, mean_items: items as f64 / elves as f64 }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "elves": self.elves,
+            "items": self.items,
+            "mean": self.mean,
+            "median": self.median,
+            "mean_items": self.mean_items
+        })
+    }
+}
+
+/// An elf in the heap of the top elves, as calories, position and snacks.
+/// The smallest of them comes first, later elves lose ties.
+type Ranked = Reverse<(i32, Reverse<usize>, Vec<i32>)>;
+
+/// Reads the elves line by line, keeping only the snacks of the `k` with
+/// the most calories. A blank line ends an elf, the last one doesn't need to
+/// be followed by one, and further blank lines are skipped. The median still needs the total of every elf, so
+/// memory grows by one number per elf.
+pub fn tally(input: &str, k: usize) -> Result<Inventory, ParseError> {
+    let mut top: BinaryHeap<Ranked> = BinaryHeap::with_capacity(k + 1);
+    let mut calories: Vec<i32> = Vec::new();
+    let mut items = 0;
+    let (mut current, mut snacks): (i32, Vec<i32>) = (0, Vec::new());
+    let mut finish = |current: i32, snacks: Vec<i32>| {
+        calories.push(current);
+        items += snacks.len();
+        // Only the snacks of elves that might make it to the top are kept
+        if top.len() < k || top.peek().is_some_and(|Reverse((least, _, _))| current > *least) {
+            top.push(Reverse((current, Reverse(calories.len()), snacks)));
+            if top.len() > k {
+                top.pop();
+            }
+        }
+    };
+    let mut lines = input.lines().peekable();
+    while let Some(line) = lines.next() {
+        let line = line.trim_end_matches('\r');
+        if !line.is_empty() {
+            let snack = number::<i32>(line)?;
+            current = current.checked_add(snack).ok_or_else(|| ParseError::at(line, "too many calories for one elf"))?;
+            snacks.push(snack);
+        }
+        if (line.is_empty() || lines.peek().is_none()) && !snacks.is_empty() {
+            finish(current, mem::take(&mut snacks));
+            current = 0;
+        }
+    }
+    if calories.is_empty() {
+        return Err(ParseError::at(input, "expected at least one elf"));
+    }
+    let top = top.into_sorted_vec()
+    .into_iter()
+    .map(|Reverse((calories, Reverse(index), snacks))| Elf { index, calories, snacks })
+    .collect();
+    Ok(Inventory { top, stats: Stats::of(&mut calories, items) })
+}
+
+/// The sum of the calories of the `k` elves carrying the most.
+pub fn top_calories(inventory: &Inventory, k: usize) -> i32 {
+    inventory.top.iter()
+    .take(k)
+    .map(|elf| elf.calories)
+    .sum()
+}
+
+/// A table of the top `n` elves by calories, with their rank, position in
+/// the input and the calories of each snack.
+pub fn report(inventory: &Inventory, n: usize) -> Vec<String> {
+    let mut lines = vec![format!("{:>4} {:>6} {:>9}  {}", "Rank", "Elf", "Calories", "Snacks")];
+    for (rank, elf) in inventory.top.iter().take(n).enumerate() {
+        let snacks: Vec<String> = elf.snacks.iter().map(|s| s.to_string()).collect();
+        lines.push(format!("{:>4} {:>6} {:>9}  {}", rank + 1, elf.index, elf.calories, snacks.join(", ")));
+    }
+    lines
+}
+
+/// The report as a list of `{rank, elf, calories, snacks}` objects.
+pub fn report_json(inventory: &Inventory, n: usize) -> Value {
+    inventory.top.iter()
+    .take(n)
+    .enumerate()
+    .map(|(rank, elf)| {
+        let mut entry = elf.to_json();
+        entry["rank"] = json!(rank + 1);
+        entry
+    })
+    .collect()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Inventory;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Inventory, ParseError> {
+        tally(input, 3)
+    }
+
+    fn part1(inventory: &Inventory) -> i32 {
+        top_calories(inventory, 1)
+    }
+
+    fn part2(inventory: &Inventory) -> i32 {
+        top_calories(inventory, 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part1(&input), 24000);
+        assert_eq!(Day01::part2(&input), 45000);
+    }
+
+    #[test]
+    fn keeps_top_elves_and_stats() {
+        let inventory = tally(EXAMPLE, 2).unwrap();
+        let top: Vec<(usize, i32, usize)> = inventory.top.iter().map(|e| (e.index, e.calories, e.snacks.len())).collect();
+        assert_eq!(top, [(4, 24000, 3), (3, 11000, 2)]);
+        assert_eq!((inventory.stats.elves, inventory.stats.items), (5, 10));
+        assert_eq!((inventory.stats.mean, inventory.stats.median), (11000.0, 10000.0));
+    }
+
+    #[test]
+    fn accepts_crlf_and_missing_separator() {
+        let unix = tally("100\n200\n\n300\n", 3).unwrap();
+        let windows = tally("100\r\n200\r\n\r\n300", 3).unwrap();
+        assert_eq!(unix, windows);
+        assert_eq!(unix.stats.elves, 2);
+        assert_eq!(unix.stats.median, 300.0);
+        assert_eq!(tally("\n100\n\n\n\n200\n\n", 3).unwrap().stats.elves, 2);
+        assert!(tally("", 3).is_err() && tally("\n\n", 3).is_err());
+        assert!(tally("1\nx\n", 3).is_err());
+    }
+
+    #[test]
+    fn reports_snacks_of_top_elves() {
+        let inventory = tally(EXAMPLE, 3).unwrap();
+        assert_eq!(report(&inventory, 2), [
+            "Rank    Elf  Calories  Snacks",
+            "   1      4     24000  7000, 8000, 9000",
+            "   2      3     11000  5000, 6000"
+        ]);
+        assert_eq!(report_json(&inventory, 1), json!([{ "rank": 1, "elf": 4, "calories": 24000, "snacks": [7000, 8000, 9000] }]));
+    }
+}
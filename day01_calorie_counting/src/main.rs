@@ -1,41 +1,72 @@
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
+use aoc_core::cli::{self, Args, DayOption, Format};
+use day01_calorie_counting::{report, report_json, tally, top_calories, Day01, Inventory};
+use serde_json::json;
 
+aoc_core::install_profiler!();
 
-fn read_backpacks(filename: &str) -> Vec<i32> {
-    let file = File::open(filename).unwrap();
-    let reader = io::BufReader::new(file);
-    let mut backpack: Vec<i32> = Vec::new();
-    let mut calories = 0;
-    for line in reader.lines() {
-        if let Ok(string) = line {
-            if string.is_empty() {
-                backpack.push(calories);
-                calories = 0;
-            } else {
-                calories += string.parse::<i32>().unwrap();
+const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input/task_1.txt");
+
+const OPTIONS: &[DayOption] = &[
+    DayOption { name: "top", value: Some("<k>"), help: "list the k elves carrying the most calories and their sum" },
+    DayOption { name: "stats", value: None, help: "print the number of elves and items and the mean and median calories" },
+    DayOption { name: "report", value: Some("<n>"), help: "list the n elves carrying the most calories with each of their snacks" }
+];
+
+fn print_top(inventory: &Inventory, k: usize, format: Format) {
+    let sum = top_calories(inventory, k);
+    match format {
+        Format::Json => {
+            let top: Vec<_> = inventory.top.iter().take(k).map(|elf| elf.to_json()).collect();
+            println!("{}", json!({ "day": 1, "k": k, "sum": sum, "top": top }));
+        },
+        Format::Text => {
+            println!("Sum of top {} elves: {}", k, sum);
+            println!("{:>6} {:>10} {:>6}", "Elf", "Calories", "Items");
+            for elf in inventory.top.iter().take(k) {
+                println!("{:>6} {:>10} {:>6}", elf.index, elf.calories, elf.snacks.len());
             }
-        } else {
-            panic!("Could not read lines.")
         }
     }
-    return backpack;
 }
 
-fn main() {
-    let input = &env::args().collect::<Vec<String>> ()[1];
-    let mut backpacks = read_backpacks(&input);
-    
-    backpacks.sort_by(|a, b| b.cmp(a));
-    println!("[Task 1] Sum of most calories: {}", backpacks[0]);
-
-    let top_3_sum: i32 = backpacks.iter()
-    .take(3)
-    .sum();
-    
-    println!("[Task 2] Sum of top 3 elves: {}", top_3_sum);
-
+fn print_stats(inventory: &Inventory, format: Format) {
+    let stats = &inventory.stats;
+    match format {
+        Format::Json => println!("{}", json!({ "day": 1, "stats": stats.to_json() })),
+        Format::Text => {
+            println!("Elves: {}, items: {} ({:.2} per elf)", stats.elves, stats.items, stats.mean_items);
+            println!("Calories per elf: mean {:.1}, median {}", stats.mean, stats.median);
+        }
+    }
+}
 
+fn print_report(inventory: &Inventory, n: usize, format: Format) {
+    match format {
+        Format::Json => println!("{}", json!({ "day": 1, "report": report_json(inventory, n) })),
+        Format::Text => {
+            for line in report(inventory, n) {
+                println!("{}", line);
+            }
+        }
+    }
 }
 
+fn main() {
+    let args = Args::from_env_with(OPTIONS);
+    let k = args.option::<usize>("top");
+    let n = args.option::<usize>("report");
+    cli::run_with::<Day01>(&args, INPUT, ["Sum of most calories", "Sum of top 3 elves"], None);
+    if k.is_none() && n.is_none() && !args.flag("stats") {
+        return;
+    }
+    let inventory = args.parse_input_with(INPUT, |input| tally(input, k.unwrap_or(0).max(n.unwrap_or(0))));
+    if let Some(k) = k {
+        print_top(&inventory, k, args.format);
+    }
+    if args.flag("stats") {
+        print_stats(&inventory, args.format);
+    }
+    if let Some(n) = n {
+        print_report(&inventory, n, args.format);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{rules::Outcome, Game};
+
+/// A way to read the second column of the strategy guide, mapping each of
+/// its codes, in the order of the codes of the game, to a different move
+/// or to a different outcome.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Reading {
+    Moves(Vec<usize>),
+    Outcomes(Vec<Outcome>)
+}
+
+/// A reading of the second column and the total score it gives.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Interpretation {
+    pub reading: Reading,
+    pub score: i32
+}
+
+/// All ordered choices of `k` different numbers below `n`, in lexicographic
+/// order. They are computed one at a time, as there are `n! / (n - k)!` of
+/// them, e.g. 13! for a guide using every code of `Rules::cyclic(13)`.
+struct Arrangements {
+    n: usize,
+    next: Option<Vec<usize>>
+}
+
+fn arrangements(n: usize, k: usize) -> Arrangements {
+    Arrangements { n, next: (k <= n).then(|| (0..k).collect()) }
+}
+
+impl Iterator for Arrangements {
+    type Item = Vec<usize>;
+
+    /// Finds the last position that can take a larger unused number, and
+    /// fills the positions after it with the smallest unused numbers.
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let current = self.next.take()?;
+        self.next = (0..current.len()).rev().find_map(|i| {
+            let larger = (current[i] + 1..self.n).find(|m| !current[..i].contains(m))?;
+            let mut next = current[..i].to_vec();
+            next.push(larger);
+            let rest: Vec<usize> = (0..self.n).filter(|m| !next.contains(m)).take(current.len() - i - 1).collect();
+            next.extend(rest);
+            Some(next)
+        });
+        Some(current)
+    }
+}
+
+/// The total score of the game when reading the second column as `reading`.
+pub fn score(game: &Game, reading: &Reading) -> i32 {
+    let rules = &game.rules;
+    game.rounds.iter()
+    .map(|round| match reading {
+        Reading::Moves(moves) => rules.score(moves[round.code], round.opponent),
+        Reading::Outcomes(outcomes) => {
+            let outcome = outcomes[round.code];
+            rules.moves[rules.response_for(round.opponent, outcome)].score + rules.outcome_score(outcome)
+        }
+    })
+    .sum()
+}
+
+/// Every reading of the second column as moves, then as outcomes, with
+/// its score, computed as they are consumed. A guide with more than three
+/// codes has no reading as outcomes.
+pub fn interpretations(game: &Game) -> impl Iterator<Item = Interpretation> + '_ {
+    let codes = game.codes.len();
+    let moves = arrangements(game.rules.moves.len(), codes)
+    .map(Reading::Moves);
+    let outcomes = arrangements(3, codes)
+    .map(|outcomes| Reading::Outcomes(outcomes.into_iter().map(|i| Outcome::ALL[i]).collect()));
+    moves.chain(outcomes)
+    .map(|reading| Interpretation { score: score(game, &reading), reading })
+}
+
+/// The readings of the second column that give a total of `target`.
+pub fn solve(game: &Game, target: i32) -> impl Iterator<Item = Interpretation> + '_ {
+    interpretations(game)
+    .filter(move |interpretation| interpretation.score == target)
+}
+
+/// The mapping of a reading, e.g. `X=Rock, Y=Paper, Z=Scissors`.
+pub fn describe(game: &Game, reading: &Reading) -> String {
+    let meanings: Vec<&str> = match reading {
+        Reading::Moves(moves) => moves.iter().map(|&m| game.rules.moves[m].name.as_str()).collect(),
+        Reading::Outcomes(outcomes) => outcomes.iter().map(|outcome| outcome.name()).collect()
+    };
+    game.codes.iter()
+    .zip(meanings)
+    .map(|(code, meaning)| format!("{}={}", code, meaning))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rules::Rules, Day02, Total};
+    use aoc_core::Solution;
+
+    #[test]
+    fn finds_readings_of_the_guide() {
+        let game = Day02::parse(include_str!("../input/task_0.txt")).unwrap();
+        let all: Vec<Interpretation> = interpretations(&game).collect();
+        assert_eq!(all.len(), 12);
+        assert_eq!(all[0], Interpretation { reading: Reading::Moves(vec![0, 1, 2]), score: 15 });
+        let outcomes = Reading::Outcomes(Outcome::ALL.to_vec());
+        assert_eq!(describe(&game, &outcomes), "X=lose, Y=draw, Z=win");
+        assert_eq!(Total::Score(score(&game, &outcomes)), Day02::part2(&game));
+        assert!(solve(&game, 12).any(|interpretation| interpretation.reading == outcomes));
+        assert!(solve(&game, 1000).next().is_none());
+        let rpsls = || Rules::parse(include_str!("../rules/rpsls.toml")).unwrap();
+        let game = crate::read_game("A X\nB V\nC X\n", rpsls()).unwrap();
+        assert_eq!(interpretations(&game).count(), 5 * 4 + 3 * 2);
+        assert_eq!(describe(&game, &Reading::Moves(vec![0, 1])), "V=Rock, X=Paper");
+        let game = crate::read_game("A V\nA W\nA X\nA Y\n", rpsls()).unwrap();
+        assert_eq!(interpretations(&game).count(), 5 * 4 * 3 * 2);
+    }
+
+    #[test]
+    fn arranges_lazily_in_order() {
+        let pairs: Vec<Vec<usize>> = arrangements(3, 2).collect();
+        assert_eq!(pairs, [[0, 1], [0, 2], [1, 0], [1, 2], [2, 0], [2, 1]]);
+        assert_eq!((arrangements(2, 0).count(), arrangements(2, 3).count()), (1, 0));
+        let mut all = arrangements(13, 13);
+        assert_eq!(all.nth(1), Some(vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12, 11]));
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_core::random::{Rng, StdRng};
+
+/// A strategy guide of `size` rounds.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+    .map(|_| format!("{} {}\n", rng.gen_range('A'..='C'), rng.gen_range('X'..='Z')))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::{Day02, Total};
+
+    /// The columns of each round as numbers from 0 to 2.
+    fn columns(input: &str) -> Vec<(i32, i32)> {
+        input.lines()
+        .map(|line| {
+            let bytes = line.as_bytes();
+            ((bytes[0] - b'A') as i32, (bytes[2] - b'X') as i32)
+        })
+        .collect()
+    }
+
+    /// Moves are numbered so that each one beats the one before it.
+    fn reference_part1(input: &str) -> i32 {
+        columns(input).iter()
+        .map(|&(opponent, response)| response + 1 + 3 * (response - opponent + 1).rem_euclid(3))
+        .sum()
+    }
+
+    fn reference_part2(input: &str) -> i32 {
+        columns(input).iter()
+        .map(|&(opponent, outcome)| 3 * outcome + (opponent + outcome - 1).rem_euclid(3) + 1)
+        .sum()
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 50, |input| {
+            let rounds = Day02::parse(input).unwrap();
+            assert_eq!(Day02::part1(&rounds), Total::Score(reference_part1(input)));
+            assert_eq!(Day02::part2(&rounds), Total::Score(reference_part2(input)));
+        });
+    }
+}
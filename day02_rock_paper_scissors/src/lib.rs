@@ -0,0 +1,177 @@
+pub mod cipher;
+pub mod generate;
+pub mod rules;
+pub mod tournament;
+
+use std::fmt;
+
+use aoc_core::{parse::next, Answer, ParseError, Solution};
+use rules::{Outcome, Rules};
+use serde_json::{json, Value};
+
+#[derive(Clone, Debug)]
+pub struct Round {
+    /// The opponent's move, as an index into the moves of the rules.
+    pub opponent: usize,
+    /// The second column, as an index into the codes of the game.
+    pub code: usize
+}
+
+/// A strategy guide together with the rules it is played by.
+#[derive(Clone, Debug)]
+pub struct Game {
+    pub rules: Rules,
+    /// The distinct codes of the second column, in alphabetical order.
+    pub codes: Vec<String>,
+    pub rounds: Vec<Round>
+}
+
+impl Game {
+    /// The second column of `round` read as the move to play.
+    pub fn response(&self, round: &Round) -> Option<usize> {
+        self.rules.response_move(&self.codes[round.code])
+    }
+
+    /// The second column of `round` read as the outcome to reach.
+    pub fn outcome(&self, round: &Round) -> Option<Outcome> {
+        self.rules.outcome(&self.codes[round.code])
+    }
+
+    /// Sums the score of each round, or names the first round whose second
+    /// column cannot be read as `reading`.
+    fn total(&self, reading: &str, score: impl Fn(&Round) -> Option<i32>) -> Total {
+        let mut total = 0;
+        for (line, round) in self.rounds.iter().enumerate() {
+            match score(round) {
+                Some(score) => total += score,
+                None => return Total::Unreadable(format!("`{}` on line {} is not {}", self.codes[round.code], line + 1, reading))
+            }
+        }
+        Total::Score(total)
+    }
+}
+
+/// Reads a strategy guide played by `rules`. Each code of the second
+/// column must be a move or an outcome, each part reading it its own way.
+pub fn read_game(input: &str, rules: Rules) -> Result<Game, ParseError> {
+    let opponent_codes: Vec<&str> = rules.moves.iter().map(|m| m.opponent.as_str()).collect();
+    let mut second_codes: Vec<&str> = rules.moves.iter().map(|m| m.response.as_str()).collect();
+    for (code, _) in &rules.outcomes {
+        if !second_codes.contains(&code.as_str()) {
+            second_codes.push(code);
+        }
+    }
+    let columns = input.lines()
+    .map(|line| {
+        let mut split = line.split_whitespace();
+        let opponent = next(&mut split, line, "the opponent's move")?;
+        let second = next(&mut split, line, "the second column")?;
+        if let Some(extra) = split.next() {
+            return Err(ParseError::at(extra, "expected two columns"));
+        }
+        let opponent = rules.opponent_move(opponent)
+        .ok_or_else(|| ParseError::at(opponent, format!("invalid move, expected one of {}", opponent_codes.join(", "))))?;
+        if !second_codes.contains(&second) {
+            return Err(ParseError::at(second, format!("invalid second column, expected one of {}", second_codes.join(", "))));
+        }
+        Ok((opponent, second))
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+    let mut codes: Vec<String> = columns.iter().map(|(_, second)| second.to_string()).collect();
+    codes.sort_unstable();
+    codes.dedup();
+    let rounds = columns.into_iter()
+    .map(|(opponent, second)| Round { opponent, code: codes.iter().position(|code| code == second).unwrap() })
+    .collect();
+    Ok(Game { rules, codes, rounds })
+}
+
+/// The total score of a part, unless the second column holds a code that
+/// the part cannot read, e.g. the response `V` of Rock when reading it as
+/// outcomes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Total {
+    Score(i32),
+    Unreadable(String)
+}
+
+impl fmt::Display for Total {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Total::Score(score) => write!(f, "{}", score),
+            Total::Unreadable(reason) => write!(f, "no score, {}", reason)
+        }
+    }
+}
+
+impl Answer for Total {
+    fn to_json(&self) -> Value {
+        match self {
+            Total::Score(score) => Value::from(*score),
+            Total::Unreadable(reason) => json!({ "error": reason })
+        }
+    }
+
+    fn is_solved(&self) -> bool {
+        matches!(self, Total::Score(_))
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Game;
+    type Part1 = Total;
+    type Part2 = Total;
+
+    fn parse(input: &str) -> Result<Game, ParseError> {
+        read_game(input, Rules::standard())
+    }
+
+    fn part1(game: &Game) -> Total {
+        game.total("a move", |round| {
+            let response = game.response(round)?;
+            Some(game.rules.score(response, round.opponent))
+        })
+    }
+
+    fn part2(game: &Game) -> Total {
+        game.total("an outcome", |round| {
+            let outcome = game.outcome(round)?;
+            let own_move = game.rules.response_for(round.opponent, outcome);
+            Some(game.rules.moves[own_move].score + game.rules.outcome_score(outcome))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part1(&input), Total::Score(15));
+        assert_eq!(Day02::part2(&input), Total::Score(12));
+    }
+
+    #[test]
+    fn plays_by_other_rules() {
+        let rpsls = || Rules::parse(include_str!("../rules/rpsls.toml")).unwrap();
+        let game = read_game("A X\nE Y\nD Z\n", rpsls()).unwrap();
+        assert_eq!(Day02::part1(&game), Total::Score(3 + 10 + 5));
+        assert_eq!(Day02::part2(&game), Total::Score(3 + 8 + 7));
+        assert_eq!(read_game("F X\n", rpsls()).unwrap_err().message, "invalid move, expected one of A, B, C, D, E");
+        assert_eq!(read_game("A U\n", rpsls()).unwrap_err().message, "invalid second column, expected one of V, W, X, Y, Z");
+        let game = read_game("A X\nC V\n", rpsls()).unwrap();
+        assert_eq!(Day02::part1(&game), Total::Score(3 + 7));
+        assert_eq!(Day02::part2(&game).to_string(), "no score, `V` on line 2 is not an outcome");
+        assert!(Day02::part1(&game).is_solved() && !Day02::part2(&game).is_solved());
+        let game = read_game("A T\n", Rules::cyclic(7).unwrap()).unwrap();
+        assert_eq!(Day02::part1(&game), Total::Score(1 + 3));
+    }
+}
@@ -1,124 +1,105 @@
-use std::{fs::File, io::{self, BufRead}};
-
-
-enum Move {
-    Rock,
-    Paper,
-    Scissors
-}
-
-impl Move {
-    fn from(code: &str) -> Move {
-        match code {
-            "A" | "X" => Move::Rock,
-            "B" | "Y" => Move::Paper,
-            "C" | "Z" => Move::Scissors,
-            _ => panic!("Invalid code.")
-        }
+use std::{fs, process::exit};
+
+use aoc_core::cli::{self, Args, DayOption, Format};
+use day02_rock_paper_scissors::{
+    cipher::{self, Interpretation, Reading},
+    read_game,
+    rules::Rules,
+    tournament::{round_robin, strategies},
+    Day02,
+    Game
+};
+use serde_json::json;
+
+aoc_core::install_profiler!();
+
+const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input/task_1.txt");
+
+const OPTIONS: &[DayOption] = &[
+    DayOption { name: "rules", value: Some("<file>"), help: "play by the rules defined in a TOML file, e.g. rules/rpsls.toml" },
+    DayOption { name: "cyclic", value: Some("<n>"), help: "play a game of n moves, each beating the moves an odd number of places before it" },
+    DayOption { name: "interpretations", value: None, help: "list the total score for every reading of the second column as moves or outcomes" },
+    DayOption { name: "target", value: Some("<score>"), help: "find the readings of the second column that give a total score" },
+    DayOption { name: "tournament", value: Some("<rounds>"), help: "let the guide, random, frequency and beat-last strategies play each other" },
+    DayOption { name: "seed", value: Some("<n>"), help: "seed the random moves of the tournament, 0 by default" }
+];
+
+fn load_rules(args: &Args) -> Result<Rules, String> {
+    match (args.option::<String>("rules"), args.option::<usize>("cyclic")) {
+        (Some(_), Some(_)) => Err("--rules and --cyclic cannot be combined".to_string()),
+        (Some(path), None) => {
+            let definition = fs::read_to_string(&path).map_err(|e| format!("could not read '{}': {}", path, e))?;
+            Rules::parse(&definition).map_err(|e| format!("invalid rules in '{}': {}", path, e))
+        },
+        (None, Some(n)) => Rules::cyclic(n),
+        (None, None) => Ok(Rules::standard())
     }
+}
 
-    fn score(&self) -> i32 {
-        match self {
-            Move::Rock => 1,
-            Move::Paper => 2,
-            Move::Scissors => 3
+/// Prints the interpretations as they are found, returning how many there were.
+fn print_interpretations(game: &Game, interpretations: impl Iterator<Item = Interpretation>, format: Format) -> usize {
+    let mut count = 0;
+    for interpretation in interpretations {
+        count += 1;
+        let kind = match interpretation.reading {
+            Reading::Moves(_) => "moves",
+            Reading::Outcomes(_) => "outcomes"
+        };
+        let mapping = cipher::describe(game, &interpretation.reading);
+        match format {
+            Format::Json => println!("{}", json!({ "day": 2, "as": kind, "mapping": mapping, "score": interpretation.score })),
+            Format::Text => println!("{:>8} {:<36} {:>8}", kind, mapping, interpretation.score)
         }
     }
+    count
+}
 
-    fn play(&self, other: &Move) -> Outcome {
-        match (&self, other) {
-                (Move::Rock, Move::Paper) => Outcome::Loose,
-                (Move::Rock, Move::Scissors) => Outcome::Win,
-                (Move::Paper, Move::Rock) => Outcome::Win,
-                (Move::Paper, Move::Scissors) => Outcome::Loose,
-                (Move::Scissors, Move::Rock) => Outcome::Loose,
-                (Move::Scissors, Move::Paper) => Outcome::Win,
-                _ => Outcome::Draw
-        }   
+fn print_tournament(game: &Game, rounds: usize, seed: u64, format: Format) {
+    let strategies = strategies(game);
+    let records = round_robin(&game.rules, &strategies, rounds, seed);
+    if format == Format::Text {
+        println!("{:<10} {:>6} {:>6} {:>6} {:>8} {:>7}", "Strategy", "Wins", "Draws", "Losses", "Score", "Mean");
     }
-
-    fn get_move_for(&self, outcome: &Outcome) -> Move {
-        match &self {
-            Move::Rock => match outcome {
-                Outcome::Loose => Move::Scissors,
-                Outcome::Draw => Move::Rock,
-                Outcome::Win => Move::Paper
-            },
-            Move::Paper => match outcome {
-                Outcome::Loose => Move::Rock,
-                Outcome::Draw => Move::Paper,
-                Outcome::Win => Move::Scissors
-            },
-            Move::Scissors => match outcome {
-                Outcome::Loose => Move::Paper,
-                Outcome::Draw => Move::Scissors,
-                Outcome::Win => Move::Rock
-            },
+    for (strategy, record) in strategies.iter().zip(&records) {
+        let mean = record.score as f64 / record.rounds().max(1) as f64;
+        match format {
+            Format::Json => println!("{}", json!({
+                "day": 2,
+                "strategy": strategy.name(),
+                "wins": record.wins,
+                "draws": record.draws,
+                "losses": record.losses,
+                "score": record.score,
+                "mean": mean
+            })),
+            Format::Text => println!(
+                "{:<10} {:>6} {:>6} {:>6} {:>8} {:>7.3}",
+                strategy.name(), record.wins, record.draws, record.losses, record.score, mean
+            )
         }
     }
 }
 
-
-
-enum Outcome {
-    Win,
-    Draw,
-    Loose
-}
-
-impl Outcome {
-    fn score(&self) -> i32 {
-        match self {
-            Self::Win => 6,
-            Self::Draw => 3,
-            Self::Loose => 0
-        }
+fn main() {
+    let args = Args::from_env_with(OPTIONS);
+    let rules = load_rules(&args).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        exit(1);
+    });
+    let target = args.option::<i32>("target");
+    let tournament = args.option::<usize>("tournament");
+    let seed = args.option::<u64>("seed").unwrap_or(0);
+    let game = cli::run_with_parser::<Day02, _>(&args, INPUT, ["Total score", "Total score"], None, |input| read_game(input, rules));
+    if args.flag("interpretations") {
+        print_interpretations(&game, cipher::interpretations(&game), args.format);
     }
-
-    fn from(str: &str) -> Outcome {
-        match str {
-            "X" => Outcome::Loose,
-            "Y" => Outcome::Draw,
-            "Z" => Outcome::Win,
-            _ => panic!("Invalid character.")
+    if let Some(target) = target {
+        if print_interpretations(&game, cipher::solve(&game, target), args.format) == 0 {
+            eprintln!("error: no reading of the second column gives a total score of {}", target);
+            exit(1);
         }
     }
-}
-
-
-fn read_input(path: &str) -> Vec<(String, String)> {
-    let file = File::open(path).unwrap();
-    let reader = io::BufReader::new(file);
-    return reader.lines()
-    .map(|result| result.unwrap())
-    .map(|line: String| {
-        let mut split = line.split_whitespace();
-        (String::from(split.next().unwrap()), String::from(split.next().unwrap()))
-    })
-    .collect();
-}
-
-fn main() {
-    let input = "inputs/task_1.txt";
-    let inputs = read_input(input);
-    // Task 1
-    let task1_score : i32 = inputs.iter()
-    .map(|cols| (Move::from(&cols.0), Move::from(&cols.1)))
-    .map(|moves| moves.1.score() + moves.1.play(&moves.0).score())
-    .sum();
-
-    println!("[Task 1] Total score: {}", task1_score);
-
-    // Task 2
-    let task2_score: i32 = inputs.iter()
-    .map(|cols| {
-        let opp_move = Move::from(&cols.0);
-        let desired_outcome = Outcome::from(&cols.1);
-        let own_move = opp_move.get_move_for(&desired_outcome);
-        return own_move.score() + desired_outcome.score();
-    })
-    .sum();
-
-    println!("[Task 2] Total score: {}", task2_score);
-
+    if let Some(rounds) = tournament {
+        print_tournament(&game, rounds, seed, args.format);
+    }
 }
@@ -0,0 +1,259 @@
+use std::collections::HashSet;
+
+use toml::{Table, Value};
+
+/// The result of a round for the own side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Outcome::Lose => "lose",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win"
+        }
+    }
+}
+
+/// One of the moves of a game.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub name: String,
+    /// The code of the move in the first column of the strategy guide.
+    pub opponent: String,
+    /// The code of the move in the second column, read as the move to play.
+    pub response: String,
+    pub score: i32,
+    /// The moves this one beats, as indices into the moves of the rules.
+    pub beats: Vec<usize>
+}
+
+/// The moves of a game, which beats which and how each round is scored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    pub moves: Vec<Move>,
+    /// The code in the second column, read as the outcome to reach, and the
+    /// score of each outcome, in the order of [`Outcome::ALL`].
+    pub outcomes: [(String, i32); 3]
+}
+
+fn string<'a>(table: &'a Table, key: &str, context: &str) -> Result<&'a str, String> {
+    table.get(key)
+    .and_then(Value::as_str)
+    .ok_or(format!("{} needs a string `{}`", context, key))
+}
+
+fn integer(table: &Table, key: &str, context: &str) -> Result<i32, String> {
+    table.get(key)
+    .and_then(Value::as_integer)
+    .and_then(|value| i32::try_from(value).ok())
+    .ok_or(format!("{} needs an integer `{}`", context, key))
+}
+
+impl Rules {
+    /// Rock, paper, scissors as played in the puzzle.
+    pub fn standard() -> Rules {
+        Rules::parse(include_str!("../rules/rps.toml")).expect("the standard rules are valid")
+    }
+
+    /// A game of `n` moves in which each move beats the moves an odd number
+    /// of places before it, in the spirit of rock, paper, scissors, which is
+    /// `cyclic(3)`. Only odd numbers of moves give every pair a winner.
+    pub fn cyclic(n: usize) -> Result<Rules, String> {
+        if n.is_multiple_of(2) || !(3..=13).contains(&n) {
+            return Err(format!("a cyclic game needs an odd number of moves from 3 to 13, not {}", n));
+        }
+        let letter = |first: u8, i: usize| ((first + i as u8) as char).to_string();
+        let moves = (0..n)
+        .map(|i| Move {
+            name: format!("Move {}", i + 1),
+            opponent: letter(b'A', i),
+            response: letter(b'Z' + 1 - n as u8, i),
+            score: i as i32 + 1,
+            beats: (1..n).step_by(2).map(|distance| (i + n - distance) % n).collect()
+        })
+        .collect();
+        let rules = Rules { moves, outcomes: [("X".to_string(), 0), ("Y".to_string(), 3), ("Z".to_string(), 6)] };
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    /// Reads rules from TOML with a table of `outcomes` with the `code` and
+    /// `score` of `lose`, `draw` and `win`, and a list of `moves` with their
+    /// `name`, `opponent` and `response` code, `score` and the names of the
+    /// moves they `beats`. See `rules/rpsls.toml` for an example.
+    pub fn parse(definition: &str) -> Result<Rules, String> {
+        let table: Table = definition.parse().map_err(|e: toml::de::Error| e.message().to_string())?;
+        let outcomes = table.get("outcomes").and_then(Value::as_table).ok_or("the rules need a table of `outcomes`")?;
+        let mut outcome_rules = Vec::new();
+        for outcome in Outcome::ALL {
+            let context = format!("outcome `{}`", outcome.name());
+            let rule = outcomes.get(outcome.name()).and_then(Value::as_table).ok_or(format!("{} is missing", context))?;
+            outcome_rules.push((string(rule, "code", &context)?.to_string(), integer(rule, "score", &context)?));
+        }
+        let definitions: Vec<&Table> = table.get("moves")
+        .and_then(Value::as_array)
+        .ok_or("the rules need a list of `moves`")?
+        .iter()
+        .map(|m| m.as_table().ok_or("each move must be a table"))
+        .collect::<Result<_, _>>()?;
+        let names: Vec<&str> = definitions.iter()
+        .enumerate()
+        .map(|(i, m)| string(m, "name", &format!("move {}", i + 1)))
+        .collect::<Result<_, _>>()?;
+        let mut moves = Vec::new();
+        for (definition, &name) in definitions.iter().zip(&names) {
+            let context = format!("move `{}`", name);
+            let beats = definition.get("beats")
+            .and_then(Value::as_array)
+            .ok_or(format!("{} needs a list of moves it `beats`", context))?
+            .iter()
+            .map(|beaten| {
+                let beaten = beaten.as_str().ok_or(format!("{} beats a move that is not a name", context))?;
+                names.iter().position(|&n| n == beaten).ok_or(format!("{} beats the unknown move `{}`", context, beaten))
+            })
+            .collect::<Result<_, String>>()?;
+            moves.push(Move {
+                name: name.to_string(),
+                opponent: string(definition, "opponent", &context)?.to_string(),
+                response: string(definition, "response", &context)?.to_string(),
+                score: integer(definition, "score", &context)?,
+                beats
+            });
+        }
+        let outcomes: [(String, i32); 3] = outcome_rules.try_into().unwrap();
+        let rules = Rules { moves, outcomes };
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    /// Checks that the codes are unambiguous, every pair of different moves
+    /// has exactly one winner and each move can be beaten and beat another,
+    /// so every outcome can be reached against it.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.moves.len() < 2 {
+            return Err("a game needs at least two moves".to_string());
+        }
+        let unique = |codes: Vec<&str>, what: &str| {
+            match codes.iter().enumerate().find(|&(i, code)| codes[..i].contains(code)) {
+                Some((_, code)) => Err(format!("the {} `{}` is used twice", what, code)),
+                None => Ok(())
+            }
+        };
+        unique(self.moves.iter().map(|m| m.name.as_str()).collect(), "name")?;
+        unique(self.moves.iter().map(|m| m.opponent.as_str()).collect(), "opponent code")?;
+        unique(self.moves.iter().map(|m| m.response.as_str()).collect(), "response code")?;
+        unique(self.outcomes.iter().map(|(code, _)| code.as_str()).collect(), "outcome code")?;
+        for (i, a) in self.moves.iter().enumerate() {
+            if a.beats.contains(&i) {
+                return Err(format!("`{}` beats itself", a.name));
+            }
+            if a.beats.iter().collect::<HashSet<_>>().len() != a.beats.len() {
+                return Err(format!("`{}` beats a move twice", a.name));
+            }
+            for (j, b) in self.moves.iter().enumerate().skip(i + 1) {
+                match (a.beats.contains(&j), b.beats.contains(&i)) {
+                    (true, true) => return Err(format!("`{}` and `{}` beat each other", a.name, b.name)),
+                    (false, false) => return Err(format!("neither of `{}` and `{}` beats the other", a.name, b.name)),
+                    _ => {}
+                }
+            }
+            if a.beats.is_empty() {
+                return Err(format!("`{}` beats no other move", a.name));
+            }
+            if a.beats.len() == self.moves.len() - 1 {
+                return Err(format!("`{}` beats every other move", a.name));
+            }
+        }
+        Ok(())
+    }
+
+    /// The outcome of playing `own` against `other`.
+    pub fn play(&self, own: usize, other: usize) -> Outcome {
+        if self.moves[own].beats.contains(&other) {
+            Outcome::Win
+        } else if self.moves[other].beats.contains(&own) {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// The first move that reaches `outcome` against `other`.
+    pub fn response_for(&self, other: usize, outcome: Outcome) -> usize {
+        (0..self.moves.len())
+        .find(|&own| self.play(own, other) == outcome)
+        .expect("valid rules reach every outcome")
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> i32 {
+        self.outcomes[outcome as usize].1
+    }
+
+    /// The own score of a round of `own` against `other`.
+    pub fn score(&self, own: usize, other: usize) -> i32 {
+        self.moves[own].score + self.outcome_score(self.play(own, other))
+    }
+
+    pub fn opponent_move(&self, code: &str) -> Option<usize> {
+        self.moves.iter().position(|m| m.opponent == code)
+    }
+
+    pub fn response_move(&self, code: &str) -> Option<usize> {
+        self.moves.iter().position(|m| m.response == code)
+    }
+
+    pub fn outcome(&self, code: &str) -> Option<Outcome> {
+        Outcome::ALL.into_iter().find(|&outcome| self.outcomes[outcome as usize].0 == code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plays_rock_paper_scissors_lizard_spock() {
+        let rules = Rules::parse(include_str!("../rules/rpsls.toml")).unwrap();
+        let [rock, paper, scissors, lizard, spock] = [0, 1, 2, 3, 4];
+        assert_eq!(rules.play(lizard, spock), Outcome::Win);
+        assert_eq!(rules.play(rock, spock), Outcome::Lose);
+        assert_eq!(rules.play(paper, paper), Outcome::Draw);
+        assert_eq!(rules.response_for(scissors, Outcome::Lose), paper);
+        assert_eq!(rules.score(spock, scissors), 11);
+    }
+
+    #[test]
+    fn builds_cyclic_games() {
+        let three = Rules::cyclic(3).unwrap();
+        let standard = Rules::standard();
+        for own in 0..3 {
+            for other in 0..3 {
+                assert_eq!(three.score(own, other), standard.score(own, other));
+            }
+        }
+        let seven = Rules::cyclic(7).unwrap();
+        assert!(seven.moves.iter().all(|m| m.beats.len() == 3));
+        assert_eq!(seven.moves[0].response, "T");
+        assert!(Rules::cyclic(4).is_err());
+    }
+
+    #[test]
+    fn rejects_inconsistent_rules() {
+        let rps = include_str!("../rules/rps.toml");
+        let invalid = |from: &str, to: &str| Rules::parse(&rps.replacen(from, to, 1)).unwrap_err();
+        assert_eq!(invalid("beats = [\"Rock\"]", "beats = []"), "neither of `Rock` and `Paper` beats the other");
+        assert_eq!(invalid("beats = [\"Rock\"]", "beats = [\"Rock\", \"Scissors\"]"), "`Paper` and `Scissors` beat each other");
+        assert_eq!(invalid("beats = [\"Scissors\"]", "beats = [\"Spock\"]"), "move `Rock` beats the unknown move `Spock`");
+        assert_eq!(invalid("beats = [\"Scissors\"]", "beats = [\"Scissors\", \"Scissors\"]"), "`Rock` beats a move twice");
+        assert_eq!(invalid("opponent = \"B\"", "opponent = \"A\""), "the opponent code `A` is used twice");
+        assert_eq!(invalid("score = 6", "points = 6"), "outcome `win` needs an integer `score`");
+    }
+}
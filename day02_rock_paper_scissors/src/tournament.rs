@@ -0,0 +1,166 @@
+use aoc_core::random::{Rng, SeedableRng, StdRng};
+
+use crate::{
+    rules::{Outcome, Rules},
+    Game
+};
+
+/// A way of choosing moves in a match, as indices into the moves of the
+/// rules.
+pub trait Strategy {
+    fn name(&self) -> &str;
+
+    /// The move for the next round, given the opponent's moves so far.
+    fn choose(&self, rules: &Rules, opponent: &[usize], rng: &mut StdRng) -> usize;
+}
+
+/// Plays the responses of a strategy guide in order, starting over at
+/// its end.
+pub struct Guide {
+    pub moves: Vec<usize>
+}
+
+impl Guide {
+    /// The guide of the rounds whose second column is a move.
+    pub fn new(game: &Game) -> Guide {
+        Guide { moves: game.rounds.iter().filter_map(|round| game.response(round)).collect() }
+    }
+}
+
+impl Strategy for Guide {
+    fn name(&self) -> &str {
+        "guide"
+    }
+
+    fn choose(&self, _: &Rules, opponent: &[usize], _: &mut StdRng) -> usize {
+        self.moves.get(opponent.len() % self.moves.len().max(1)).copied().unwrap_or(0)
+    }
+}
+
+pub struct Random;
+
+impl Strategy for Random {
+    fn name(&self) -> &str {
+        "random"
+    }
+
+    fn choose(&self, rules: &Rules, _: &[usize], rng: &mut StdRng) -> usize {
+        rng.gen_range(0..rules.moves.len())
+    }
+}
+
+/// Beats the move the opponent played most often, with ties going to the
+/// first of the moves.
+pub struct Frequency;
+
+impl Strategy for Frequency {
+    fn name(&self) -> &str {
+        "frequency"
+    }
+
+    fn choose(&self, rules: &Rules, opponent: &[usize], rng: &mut StdRng) -> usize {
+        if opponent.is_empty() {
+            return Random.choose(rules, opponent, rng);
+        }
+        let mut counts = vec![0; rules.moves.len()];
+        for &m in opponent {
+            counts[m] += 1;
+        }
+        let most = (0..counts.len()).rev().max_by_key(|&m| counts[m]).unwrap();
+        rules.response_for(most, Outcome::Win)
+    }
+}
+
+/// Beats the opponent's last move.
+pub struct BeatLast;
+
+impl Strategy for BeatLast {
+    fn name(&self) -> &str {
+        "beat-last"
+    }
+
+    fn choose(&self, rules: &Rules, opponent: &[usize], rng: &mut StdRng) -> usize {
+        match opponent.last() {
+            Some(&last) => rules.response_for(last, Outcome::Win),
+            None => Random.choose(rules, opponent, rng)
+        }
+    }
+}
+
+/// The four strategies, with the guide playing the responses of `game`.
+pub fn strategies(game: &Game) -> Vec<Box<dyn Strategy>> {
+    vec![Box::new(Guide::new(game)), Box::new(Random), Box::new(Frequency), Box::new(BeatLast)]
+}
+
+/// The results of one strategy over all its matches.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Record {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub score: i64
+}
+
+impl Record {
+    fn add(&mut self, outcome: Outcome, score: i32) {
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Lose => self.losses += 1
+        }
+        self.score += score as i64;
+    }
+
+    pub fn rounds(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+}
+
+/// Lets every strategy play every other one for `rounds` rounds, drawing
+/// random moves from a generator seeded with `seed`. The records are in
+/// the order of the strategies.
+pub fn round_robin(rules: &Rules, strategies: &[Box<dyn Strategy>], rounds: usize, seed: u64) -> Vec<Record> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut records = vec![Record::default(); strategies.len()];
+    for a in 0..strategies.len() {
+        for b in a + 1..strategies.len() {
+            let (mut moves_a, mut moves_b) = (Vec::with_capacity(rounds), Vec::with_capacity(rounds));
+            for _ in 0..rounds {
+                let move_a = strategies[a].choose(rules, &moves_b, &mut rng);
+                let move_b = strategies[b].choose(rules, &moves_a, &mut rng);
+                records[a].add(rules.play(move_a, move_b), rules.score(move_a, move_b));
+                records[b].add(rules.play(move_b, move_a), rules.score(move_b, move_a));
+                moves_a.push(move_a);
+                moves_b.push(move_b);
+            }
+        }
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_core::Solution;
+
+    #[test]
+    fn beats_predictable_players() {
+        let rules = Rules::standard();
+        for adaptive in [Box::new(BeatLast) as Box<dyn Strategy>, Box::new(Frequency)] {
+            let players: Vec<Box<dyn Strategy>> = vec![Box::new(Guide { moves: vec![0] }), adaptive];
+            let records = round_robin(&rules, &players, 100, 7);
+            // Only the random first move can fail to beat rock
+            assert!(records[1].wins >= 99 && records[0].losses >= 99);
+            assert_eq!((records[0].wins, records[0].draws), (records[1].losses, records[1].draws));
+        }
+    }
+
+    #[test]
+    fn plays_reproducibly() {
+        let game = Day02::parse(include_str!("../input/task_0.txt")).unwrap();
+        let first = round_robin(&game.rules, &strategies(&game), 50, 1);
+        assert_eq!(first, round_robin(&game.rules, &strategies(&game), 50, 1));
+        assert!(first.iter().all(|record| record.rounds() == 150));
+    }
+}
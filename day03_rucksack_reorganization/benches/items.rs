@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use aoc_core::{random::generate, Solution};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day03_rucksack_reorganization::{generate::generate as rucksacks, Day03};
+
+/// Both parts as they were solved with a `HashSet<char>` per rucksack and a
+/// linear search for priorities, to compare the item sets against.
+mod hash_sets {
+    use super::*;
+
+    fn score(item: &char) -> i32 {
+        let mut priority_list: Vec<char> = ('a'..='z').collect();
+        priority_list.append(&mut ('A'..='Z').collect::<Vec<char>>());
+        (priority_list.iter().position(|e| e == item).unwrap() + 1) as i32
+    }
+
+    pub fn part1(rucksacks: &[String]) -> i32 {
+        rucksacks.iter()
+        .map(|rucksack| {
+            let (first, second) = rucksack.split_at(rucksack.len() / 2);
+            let first: HashSet<char> = first.chars().collect();
+            let second: HashSet<char> = second.chars().collect();
+            score(first.intersection(&second).next().unwrap())
+        })
+        .sum()
+    }
+
+    pub fn part2(rucksacks: &[String]) -> i32 {
+        rucksacks.chunks(3)
+        .map(|group| {
+            let badges = group.iter()
+            .map(|rucksack| rucksack.chars().collect::<HashSet<char>>())
+            .reduce(|a, b| a.intersection(&b).copied().collect())
+            .unwrap();
+            score(badges.iter().next().unwrap())
+        })
+        .sum()
+    }
+}
+
+/// Compares both parts on generated inputs of up to 30,000 rucksacks.
+fn items(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day03");
+    for groups in [100, 1_000, 10_000] {
+        let input = Day03::parse(&generate(rucksacks, 1, groups)).unwrap();
+        group.bench_with_input(BenchmarkId::new("part1/hash_set", groups), &input, |b, input| b.iter(|| hash_sets::part1(&input.rucksacks)));
+        group.bench_with_input(BenchmarkId::new("part1/item_set", groups), &input, |b, input| b.iter(|| Day03::part1(input)));
+        group.bench_with_input(BenchmarkId::new("part2/hash_set", groups), &input, |b, input| b.iter(|| hash_sets::part2(&input.rucksacks)));
+        group.bench_with_input(BenchmarkId::new("part2/item_set", groups), &input, |b, input| b.iter(|| Day03::part2(input)));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, items);
+criterion_main!(benches);
@@ -0,0 +1,106 @@
+use serde_json::{json, Value};
+
+use crate::{get_badge, get_common_item, items::priority, Rucksacks};
+
+/// The item found in all compartments of the rucksack on a line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misplaced {
+    /// The line of the rucksack, starting at 1.
+    pub line: usize,
+    pub item: char
+}
+
+/// The badge of a group of rucksacks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Badge {
+    /// The number of the group, starting at 1.
+    pub group: usize,
+    /// The first and last line of the group's rucksacks.
+    pub lines: (usize, usize),
+    pub item: char
+}
+
+/// Every misplaced item and badge, and how often each item was misplaced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Audit {
+    pub misplaced: Vec<Misplaced>,
+    pub badges: Vec<Badge>,
+    /// Each misplaced item type and in how many rucksacks, most often
+    /// first and by priority on ties.
+    pub counts: Vec<(char, usize)>
+}
+
+pub fn audit(rucksacks: &Rucksacks) -> Audit {
+    let misplaced: Vec<Misplaced> = rucksacks.rucksacks.iter()
+    .enumerate()
+    .map(|(i, rucksack)| Misplaced { line: i + 1, item: get_common_item(rucksack, rucksacks.layout.compartments).unwrap() })
+    .collect();
+    let size = rucksacks.layout.group;
+    let badges = rucksacks.groups()
+    .enumerate()
+    .map(|(i, group)| Badge { group: i + 1, lines: (i * size + 1, (i + 1) * size), item: get_badge(group).unwrap() })
+    .collect();
+    let mut counts = [0; 53];
+    for m in &misplaced {
+        counts[priority(m.item).unwrap() as usize] += 1;
+    }
+    let mut counts: Vec<(char, usize)> = ('a'..='z').chain('A'..='Z')
+    .map(|item| (item, counts[priority(item).unwrap() as usize]))
+    .filter(|&(_, count)| count > 0)
+    .collect();
+    counts.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+    Audit { misplaced, badges, counts }
+}
+
+impl Audit {
+    /// The findings as tables of rucksacks, groups and item counts.
+    pub fn table(&self) -> Vec<String> {
+        let mut lines = vec![format!("{:>6}  {:<9} {:>8}", "Line", "Misplaced", "Priority")];
+        for m in &self.misplaced {
+            lines.push(format!("{:>6}  {:<9} {:>8}", m.line, m.item, priority(m.item).unwrap()));
+        }
+        lines.push(String::new());
+        lines.push(format!("{:>6}  {:<13} {:<5} {:>8}", "Group", "Lines", "Badge", "Priority"));
+        for badge in &self.badges {
+            let range = format!("{}-{}", badge.lines.0, badge.lines.1);
+            lines.push(format!("{:>6}  {:<13} {:<5} {:>8}", badge.group, range, badge.item, priority(badge.item).unwrap()));
+        }
+        lines.push(String::new());
+        lines.push(format!("{:>6}  {:>9}", "Item", "Rucksacks"));
+        for (item, count) in &self.counts {
+            lines.push(format!("{:>6}  {:>9}", item, count));
+        }
+        lines
+    }
+
+    pub fn to_json(&self) -> Value {
+        let misplaced: Vec<Value> = self.misplaced.iter()
+        .map(|m| json!({ "line": m.line, "item": m.item, "priority": priority(m.item) }))
+        .collect();
+        let badges: Vec<Value> = self.badges.iter()
+        .map(|b| json!({ "group": b.group, "lines": [b.lines.0, b.lines.1], "item": b.item, "priority": priority(b.item) }))
+        .collect();
+        let counts: Vec<Value> = self.counts.iter()
+        .map(|&(item, count)| json!({ "item": item, "rucksacks": count }))
+        .collect();
+        json!({ "misplaced": misplaced, "badges": badges, "counts": counts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day03;
+    use aoc_core::Solution;
+
+    #[test]
+    fn lists_items_and_badges() {
+        let audit = audit(&Day03::parse(include_str!("../input/task_0.txt")).unwrap());
+        let misplaced: String = audit.misplaced.iter().map(|m| m.item).collect();
+        assert_eq!(misplaced, "pLPvts");
+        assert_eq!(audit.badges[1], Badge { group: 2, lines: (4, 6), item: 'Z' });
+        assert_eq!(audit.counts[..2], [('p', 1), ('s', 1)]);
+        assert_eq!(audit.table()[1], "     1  p               16");
+        assert_eq!(audit.to_json()["badges"][0], json!({ "group": 1, "lines": [1, 3], "item": "r", "priority": 18 }));
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+
+use aoc_core::random::{Rng, SliceRandom, StdRng};
+
+fn item(rng: &mut StdRng) -> char {
+    *[rng.gen_range('a'..='z'), rng.gen_range('A'..='Z')].choose(rng).unwrap()
+}
+
+/// A rucksack whose compartments share `common` and which carries `badge`.
+fn rucksack(rng: &mut StdRng, common: char, badge: char) -> (String, String) {
+    let size = rng.gen_range(1..=8);
+    let mut compartments = [Vec::new(), Vec::new()];
+    for compartment in &mut compartments {
+        compartment.extend((1..size).map(|_| item(rng)));
+        compartment.push(common);
+    }
+    if badge != common {
+        let side = rng.gen_range(0..2);
+        let i = rng.gen_range(0..size);
+        compartments[side][i] = badge;
+    }
+    for compartment in &mut compartments {
+        compartment.shuffle(rng);
+    }
+    let [first, second] = compartments;
+    (first.into_iter().collect(), second.into_iter().collect())
+}
+
+fn items(s: &str) -> HashSet<char> {
+    s.chars().collect()
+}
+
+/// `size` groups of three rucksacks. Each rucksack has exactly one item in
+/// both compartments and each group exactly one item in all rucksacks.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let badge = item(rng);
+        let group = loop {
+            let group: Vec<(String, String)> = (0..3)
+            .map(|_| {
+                let common = if rng.gen_bool(0.2) { badge } else { item(rng) };
+                rucksack(rng, common, badge)
+            })
+            .collect();
+            let unique_common = group.iter()
+            .all(|(first, second)| items(first).intersection(&items(second)).count() == 1);
+            let badges = group.iter()
+            .map(|(first, second)| items(&(first.clone() + second)))
+            .reduce(|a, b| a.intersection(&b).copied().collect())
+            .unwrap();
+            if unique_common && badges.len() == 1 {
+                break group;
+            }
+        };
+        for (first, second) in group {
+            input += &format!("{}{}\n", first, second);
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day03;
+
+    fn priority(item: char) -> i32 {
+        if item.is_ascii_lowercase() {
+            (item as u8 - b'a' + 1) as i32
+        } else {
+            (item as u8 - b'A' + 27) as i32
+        }
+    }
+
+    fn reference_part1(input: &str) -> i32 {
+        input.lines()
+        .map(|line| {
+            let (first, second) = line.split_at(line.len() / 2);
+            first.chars().find(|&c| second.contains(c)).map(priority).unwrap()
+        })
+        .sum()
+    }
+
+    fn reference_part2(input: &str) -> i32 {
+        let lines: Vec<&str> = input.lines().collect();
+        lines.chunks(3)
+        .map(|group| group[0].chars().find(|&c| group[1..].iter().all(|r| r.contains(c))).map(priority).unwrap())
+        .sum()
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 20, |input| {
+            let rucksacks = Day03::parse(input).unwrap();
+            assert_eq!(Day03::part1(&rucksacks), reference_part1(input));
+            assert_eq!(Day03::part2(&rucksacks), reference_part2(input));
+        });
+    }
+}
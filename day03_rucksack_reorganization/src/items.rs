@@ -0,0 +1,111 @@
+use std::{
+    fmt,
+    ops::{BitAnd, BitOr}
+};
+
+/// The priority of an item, 1 to 26 for `a` to `z` and 27 to 52 for `A`
+/// to `Z`, or `None` for anything else.
+pub fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None
+    }
+}
+
+/// The item with a priority.
+fn item(priority: u32) -> char {
+    match priority {
+        1..=26 => (b'a' + (priority - 1) as u8) as char,
+        _ => (b'A' + (priority - 27) as u8) as char
+    }
+}
+
+/// A set of item types, with bit `p` set for the item of priority `p`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    /// The items of a rucksack or compartment, which must be letters.
+    pub fn of(items: &str) -> ItemSet {
+        items.chars().collect()
+    }
+
+    pub fn insert(&mut self, item: char) {
+        let priority = priority(item).expect("items are letters");
+        self.0 |= 1 << priority;
+    }
+
+    pub fn contains(self, item: char) -> bool {
+        priority(item).is_some_and(|priority| self.0 & 1 << priority != 0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The item of the lowest priority.
+    pub fn first(self) -> Option<char> {
+        (!self.is_empty()).then(|| item(self.0.trailing_zeros()))
+    }
+
+    /// The items by increasing priority.
+    pub fn items(self) -> impl Iterator<Item = char> {
+        (1..=52).filter(move |&p| self.0 & 1 << p != 0).map(item)
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+}
+
+impl FromIterator<char> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = char>>(items: I) -> ItemSet {
+        let mut set = ItemSet::EMPTY;
+        for item in items {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+impl fmt::Debug for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.items()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combines_sets() {
+        let first = ItemSet::of("vJrwpWtwJgWr");
+        let second = ItemSet::of("hcsFMMfFFhFp");
+        assert_eq!(first & second, ItemSet::of("p"));
+        assert_eq!((first | second).len(), 14);
+        assert_eq!(ItemSet::of("zaZA").items().collect::<String>(), "azAZ");
+        assert_eq!(ItemSet::of("QL").first(), Some('L'));
+        assert!(ItemSet::EMPTY.first().is_none() && !first.contains('1'));
+        assert_eq!((priority('a'), priority('Z'), priority('-')), (Some(1), Some(52), None));
+    }
+}
@@ -0,0 +1,163 @@
+pub mod audit;
+pub mod generate;
+pub mod items;
+
+use aoc_core::{ParseError, Solution};
+use items::{priority, ItemSet};
+
+/// How the items of a rucksack are split into compartments and how many
+/// rucksacks make up a group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Layout {
+    pub compartments: usize,
+    pub group: usize
+}
+
+impl Layout {
+    pub fn new(compartments: usize, group: usize) -> Result<Layout, String> {
+        if compartments == 0 || group == 0 {
+            return Err("rucksacks need at least one compartment and groups at least one rucksack".to_string());
+        }
+        Ok(Layout { compartments, group })
+    }
+}
+
+impl Default for Layout {
+    fn default() -> Layout {
+        Layout { compartments: 2, group: 3 }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rucksacks {
+    pub layout: Layout,
+    pub rucksacks: Vec<String>
+}
+
+impl Rucksacks {
+    pub fn groups(&self) -> impl Iterator<Item = &[String]> {
+        self.rucksacks.chunks(self.layout.group)
+    }
+}
+
+/// Reads rucksacks that split evenly into the compartments of `layout` and
+/// make up complete groups, each rucksack with exactly one item in all its
+/// compartments and each group with exactly one item in all its rucksacks.
+pub fn read_rucksacks(input: &str, layout: Layout) -> Result<Rucksacks, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    for &line in &lines {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::at(&line[i..i + c.len_utf8()], "invalid item, expected a letter"));
+        }
+        if line.len() % layout.compartments != 0 {
+            let message = format!("rucksack of {} items cannot be split into {} equal compartments", line.len(), layout.compartments);
+            return Err(ParseError::at(line, message));
+        }
+        get_common_item(line, layout.compartments).map_err(|e| ParseError::at(line, e))?;
+    }
+    for group in lines.chunks(layout.group) {
+        if group.len() < layout.group {
+            let message = format!("incomplete group of {} rucksacks, expected {}", group.len(), layout.group);
+            return Err(ParseError::at(group[0], message));
+        }
+        get_badge(group).map_err(|e| ParseError::at(group[0], e))?;
+    }
+    Ok(Rucksacks { layout, rucksacks: lines.into_iter().map(String::from).collect() })
+}
+
+/// The only item in `items`, shared by all of `owners`.
+fn single(items: ItemSet, owners: &str) -> Result<char, String> {
+    match items.len() {
+        0 => Err(format!("the {} share no item", owners)),
+        1 => Ok(items.first().unwrap()),
+        _ => {
+            let items: Vec<String> = items.items().map(String::from).collect();
+            Err(format!("the {} share several items: {}", owners, items.join(", ")))
+        }
+    }
+}
+
+/// The `n` compartments of a rucksack.
+pub fn compartments(rucksack: &str, n: usize) -> impl Iterator<Item = &str> {
+    let size = (rucksack.len() / n).max(1);
+    (0..rucksack.len()).step_by(size).map(move |start| &rucksack[start..start + size])
+}
+
+/// The item in all `n` compartments of a rucksack.
+pub fn get_common_item(rucksack: &str, n: usize) -> Result<char, String> {
+    let common = compartments(rucksack, n)
+    .map(ItemSet::of)
+    .reduce(|a, b| a & b)
+    .unwrap_or_default();
+    single(common, "compartments")
+}
+
+/// The item in all rucksacks of a group.
+pub fn get_badge<S: AsRef<str>>(group: &[S]) -> Result<char, String> {
+    let common = group.iter()
+    .map(|rucksack| ItemSet::of(rucksack.as_ref()))
+    .reduce(|a, b| a & b)
+    .unwrap_or_default();
+    single(common, "rucksacks of the group")
+}
+
+fn score(item: char) -> i32 {
+    priority(item).unwrap() as i32
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Rucksacks;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Rucksacks, ParseError> {
+        read_rucksacks(input, Layout::default())
+    }
+
+    fn part1(rucksacks: &Rucksacks) -> i32 {
+        rucksacks.rucksacks.iter()
+        .map(|r| get_common_item(r, rucksacks.layout.compartments))
+        .map(|e| score(e.unwrap()))
+        .sum()
+    }
+
+    fn part2(rucksacks: &Rucksacks) -> i32 {
+        rucksacks.groups()
+        .map(get_badge) // retrieve badges
+        .map(|e| score(e.unwrap())) // compute score of each badge
+        .sum() // compute sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part1(&input), 157);
+        assert_eq!(Day03::part2(&input), 70);
+    }
+
+    #[test]
+    fn splits_into_any_layout() {
+        let layout = Layout::new(3, 2).unwrap();
+        let rucksacks = read_rucksacks("abXcdXefX\nXgXhXi\n", layout).unwrap();
+        assert_eq!(compartments("abXcdXefX", 3).collect::<Vec<_>>(), ["abX", "cdX", "efX"]);
+        assert_eq!((Day03::part1(&rucksacks), Day03::part2(&rucksacks)), (100, 50));
+        let error = |input: &str| read_rucksacks(input, layout).unwrap_err().message;
+        assert_eq!(error("aXbXcX\n"), "incomplete group of 1 rucksacks, expected 2");
+        assert_eq!(error("abXcd\n"), "rucksack of 5 items cannot be split into 3 equal compartments");
+        assert_eq!(error("abcdef\n"), "the compartments share no item");
+        assert_eq!(error("abBabBabB\nBab\n"), "the compartments share several items: a, b, B");
+        assert_eq!(error("aaa\nbbb\n"), "the rucksacks of the group share no item");
+        assert!(Layout::new(0, 3).is_err());
+    }
+}
@@ -1,52 +1,39 @@
-use std::{fs::File, io::{self, BufRead}, collections::HashSet};
+use std::process::exit;
 
-fn read_input(path: &str) -> Vec<String> {
-    let file = File::open(path).unwrap();
-    let reader = io::BufReader::new(file);
-    return reader.lines().map(|l| l.unwrap()).collect();
-}
+use aoc_core::cli::{self, Args, DayOption, Format};
+use day03_rucksack_reorganization::{audit::audit, read_rucksacks, Day03, Layout};
+use serde_json::json;
 
-fn get_common_item(rucksack: &String) -> Option<char> {
-    let compartments = rucksack.split_at(rucksack.len() / 2);
-    let c1: HashSet<char> = compartments.0.chars().collect();
-    let c2: HashSet<char> = compartments.1.chars().collect();
-    let mut common = c1.intersection(&c2);
-    if let Some(elem) = common.next() {
-        return Option::Some(elem.clone());
-    }
-    return Option::None;
-    
-}
-
-fn get_badge(group: &Vec<String>) -> char {
-    let intersection: HashSet<char> = group.iter()
-    .map(|e| e.chars().collect::<HashSet<char>>()) // map to hashsets of chars
-    .reduce(|a,b| {
-        let int = a.intersection(&b); // compute intersection of all
-        return int.map(|&c| char::from(c)).collect();
-    }).unwrap(); // Get resulting hashset
-    return *intersection.iter().next().unwrap(); // retrieve (hopefully) the single left item
-}
+aoc_core::install_profiler!();
 
-fn score(item: &char) -> i32 {
-    let mut priority_list: Vec<char> = ('a'..='z').collect();
-    priority_list.append(&mut('A'..='Z').collect::<Vec<char>>());
-    return (priority_list.iter().position(|e| e == item).unwrap() + 1) as i32;
-}
+const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input/task_1.txt");
 
+const OPTIONS: &[DayOption] = &[
+    DayOption { name: "compartments", value: Some("<n>"), help: "split each rucksack into n equal compartments, 2 by default" },
+    DayOption { name: "group", value: Some("<n>"), help: "look for badges in groups of n rucksacks, 3 by default" },
+    DayOption { name: "audit", value: None, help: "list the misplaced item of each rucksack, the badge of each group and how often each item was misplaced" }
+];
 
 fn main() {
-    let rucksacks = read_input("./inputs/task_1.txt");
-    let task1_sum: i32 = rucksacks.iter()
-    .map(get_common_item)
-    .map(|e| score(&e.unwrap()))
-    .sum();
-    println!("[Task 1] Priority sum: {}", task1_sum);
-
-    let task2_sum: i32 = rucksacks
-    .chunks(3) // Iterate over groups of 3
-    .map(|c| get_badge(&c.to_vec())) // retrieve badges
-    .map(|e| score(&e)) // compute score of each badge
-    .sum(); // compute sum
-    println!("[Task 2] Priority sum: {}", task2_sum);
-}
\ No newline at end of file
+    let args = Args::from_env_with(OPTIONS);
+    let default = Layout::default();
+    let compartments = args.option::<usize>("compartments").unwrap_or(default.compartments);
+    let group = args.option::<usize>("group").unwrap_or(default.group);
+    let layout = Layout::new(compartments, group).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        exit(2);
+    });
+    let rucksacks = cli::run_with_parser::<Day03, _>(&args, INPUT, ["Priority sum", "Priority sum"], None, |input| read_rucksacks(input, layout));
+    if !args.flag("audit") {
+        return;
+    }
+    let audit = audit(&rucksacks);
+    match args.format {
+        Format::Json => println!("{}", json!({ "day": 3, "audit": audit.to_json() })),
+        Format::Text => {
+            for line in audit.table() {
+                println!("{}", line);
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_core::{random::generate, Solution};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day04_camp_cleanup::{
+    generate::generate as assignments,
+    interval::Interval,
+    sweep::{count_overlapping_pairs, elves, peak},
+    Day04
+};
+
+/// Counts overlapping pairs by comparing every elf with every other one.
+fn count_quadratic(elves: &[Interval]) -> u64 {
+    (0..elves.len())
+    .map(|i| (i + 1..elves.len()).filter(|&j| elves[i].overlaps(&elves[j])).count() as u64)
+    .sum()
+}
+
+/// Compares the sweeps with pairwise comparison on generated inputs, the
+/// latter only up to 20,000 elves.
+fn sweep(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day04");
+    group.sample_size(10);
+    for lines in [1_000, 10_000, 1_000_000] {
+        let elves = elves(&Day04::parse(&generate(assignments, 1, lines)).unwrap());
+        if lines <= 10_000 {
+            group.bench_with_input(BenchmarkId::new("pairs/quadratic", lines), &elves, |b, elves| b.iter(|| count_quadratic(elves)));
+        }
+        group.bench_with_input(BenchmarkId::new("pairs/sweep", lines), &elves, |b, elves| b.iter(|| count_overlapping_pairs(elves)));
+        group.bench_with_input(BenchmarkId::new("peak/sweep", lines), &elves, |b, elves| b.iter(|| peak(elves)));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, sweep);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+use aoc_core::random::{Rng, StdRng};
+
+fn assignment(rng: &mut StdRng) -> (i32, i32) {
+    let start = rng.gen_range(1..=99);
+    (start, rng.gen_range(start..=99.min(start + 30)))
+}
+
+/// `size` pairs of assignments, some of them identical or a single section.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+    .map(|_| {
+        let a = assignment(rng);
+        let b = if rng.gen_bool(0.1) { a } else { assignment(rng) };
+        format!("{}-{},{}-{}\n", a.0, a.1, b.0, b.1)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day04;
+
+    /// The sections of both assignments of each pair.
+    fn sections(input: &str) -> Vec<(HashSet<i32>, HashSet<i32>)> {
+        input.lines()
+        .map(|line| {
+            let numbers: Vec<i32> = line.split([',', '-']).map(|n| n.parse().unwrap()).collect();
+            ((numbers[0]..=numbers[1]).collect(), (numbers[2]..=numbers[3]).collect())
+        })
+        .collect()
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 50, |input| {
+            let assignments = Day04::parse(input).unwrap();
+            let pairs = sections(input);
+            let containing = pairs.iter().filter(|(a, b)| a.is_subset(b) || b.is_subset(a)).count();
+            let overlapping = pairs.iter().filter(|(a, b)| !a.is_disjoint(b)).count();
+            assert_eq!(Day04::part1(&assignments), containing);
+            assert_eq!(Day04::part2(&assignments), overlapping);
+        });
+    }
+}
@@ -0,0 +1,220 @@
+use std::{fmt, str::FromStr};
+
+use aoc_core::{parse::{number, split_once}, ParseError};
+
+/// The sections from `start` to `end`, both included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i32,
+    pub end: i32
+}
+
+impl Interval {
+    /// The interval from `start` to `end`, if it isn't empty.
+    pub fn new(start: i32, end: i32) -> Option<Interval> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    /// Reads an interval written as `start-end`.
+    pub fn parse(text: &str) -> Result<Interval, ParseError> {
+        let (start, end) = split_once(text, "-")?;
+        Interval::new(number(start)?, number(end)?).ok_or_else(|| ParseError::at(text, "the range ends before it starts"))
+    }
+
+    /// The number of sections, which is never zero.
+    pub fn length(&self) -> usize {
+        (self.end as i64 - self.start as i64 + 1) as usize
+    }
+
+    pub fn contains(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn contains_section(&self, section: i32) -> bool {
+        self.start <= section && section <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn union(&self, other: &Interval) -> IntervalSet {
+        IntervalSet::from_iter([*self, *other])
+    }
+
+    /// The sections of this interval that are not in `other`.
+    pub fn difference(&self, other: &Interval) -> IntervalSet {
+        IntervalSet::from(*self).difference(&IntervalSet::from(*other))
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Interval, ParseError> {
+        Interval::parse(text)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// A set of sections as ordered intervals that neither overlap nor touch.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>
+}
+
+impl IntervalSet {
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// The number of sections.
+    pub fn len(&self) -> usize {
+        self.intervals.iter().map(Interval::length).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains_section(&self, section: i32) -> bool {
+        let i = self.intervals.partition_point(|interval| interval.end < section);
+        self.intervals.get(i).is_some_and(|interval| interval.contains_section(section))
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals.iter().chain(&other.intervals).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (mut i, mut j) = (0, 0);
+        let mut intervals = Vec::new();
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            intervals.extend(a.intersection(&b));
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The sections in this set but not in `other`. The pieces are found in
+    /// `i64`, so that cuts at the ends of `i32` don't overflow.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let piece = |start: i64, end: i64| (start <= end).then_some(Interval { start: start as i32, end: end as i32 });
+        let mut intervals = Vec::new();
+        let mut removed = other.intervals.iter().peekable();
+        for &interval in &self.intervals {
+            let (mut start, end) = (interval.start as i64, interval.end as i64);
+            while let Some(cut) = removed.peek() {
+                if (cut.end as i64) < start {
+                    removed.next();
+                    continue;
+                }
+                if cut.start > interval.end {
+                    break;
+                }
+                intervals.extend(piece(start, cut.start as i64 - 1));
+                start = cut.end as i64 + 1;
+                if cut.end >= interval.end {
+                    break;
+                }
+                removed.next();
+            }
+            intervals.extend(piece(start, end));
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The sections of `range` that are not in this set.
+    pub fn gaps(&self, range: Interval) -> IntervalSet {
+        IntervalSet::from(range).difference(self)
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> IntervalSet {
+        IntervalSet { intervals: vec![interval] }
+    }
+}
+
+/// Merges intervals into a set, joining those that overlap or touch.
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> IntervalSet {
+        let mut sorted: Vec<Interval> = intervals.into_iter().collect();
+        sorted.sort_unstable();
+        let mut merged: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match merged.last_mut() {
+                Some(last) if interval.start as i64 <= last.end as i64 + 1 => last.end = last.end.max(interval.end),
+                _ => merged.push(interval)
+            }
+        }
+        IntervalSet { intervals: merged }
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let intervals: Vec<String> = self.intervals.iter().map(Interval::to_string).collect();
+        write!(f, "{}", intervals.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(text: &str) -> Interval {
+        text.parse().unwrap()
+    }
+
+    fn set(text: &str) -> IntervalSet {
+        text.split(", ").map(interval).collect()
+    }
+
+    #[test]
+    fn combines_intervals() {
+        let (a, b) = (interval("2-6"), interval("4-8"));
+        assert_eq!(a.intersection(&b), Some(interval("4-6")));
+        assert_eq!(a.intersection(&interval("7-9")), None);
+        assert_eq!(a.union(&interval("7-9")), set("2-9"));
+        assert_eq!(a.difference(&interval("3-4")), set("2-2, 5-6"));
+        assert!(interval("2-8").contains(&interval("3-7")) && !a.contains(&b));
+        assert_eq!((a.length(), interval("5-5").length()), (5, 1));
+        assert!(Interval::parse("6-4").is_err());
+    }
+
+    #[test]
+    fn merges_sets() {
+        let covered = set("10-12, 1-3, 2-5, 7-8");
+        assert_eq!(covered.to_string(), "1-5, 7-8, 10-12");
+        assert_eq!(covered.len(), 10);
+        assert_eq!(covered.gaps(interval("0-13")), set("0-0, 6-6, 9-9, 13-13"));
+        assert_eq!(covered.intersection(&set("3-7, 12-20")), set("3-5, 7-7, 12-12"));
+        assert_eq!(covered.difference(&set("2-2, 4-11")), set("1-1, 3-3, 12-12"));
+        assert_eq!(covered.union(&set("6-6, 9-9")), set("1-12"));
+        assert!(covered.contains_section(8) && !covered.contains_section(9));
+    }
+
+    #[test]
+    fn cuts_at_the_ends_of_i32() {
+        let all = Interval { start: i32::MIN, end: i32::MAX };
+        assert_eq!(all.difference(&all), IntervalSet::default());
+        assert_eq!(all.difference(&interval("0-2147483647")), IntervalSet::from(Interval { start: i32::MIN, end: -1 }));
+        assert_eq!(IntervalSet::from(all).gaps(interval("1-2147483647")), IntervalSet::default());
+        assert_eq!(set("1-2, 5-2147483647").gaps(interval("0-2147483647")), set("0-0, 3-4"));
+    }
+}
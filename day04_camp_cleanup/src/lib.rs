@@ -0,0 +1,103 @@
+pub mod generate;
+pub mod interval;
+pub mod sweep;
+
+use aoc_core::{parse::split_once, ParseError, Solution};
+use interval::{Interval, IntervalSet};
+
+type Assignment = Interval;
+
+fn parse_line(line: &str) -> Result<(Assignment, Assignment), ParseError> {
+    let (a, b) = split_once(line, ",")?;
+    Ok((Interval::parse(a)?, Interval::parse(b)?))
+}
+
+fn read_shifts(input: &str) -> Result<Vec<(Assignment, Assignment)>, ParseError> {
+    input.lines()
+    .map(parse_line)
+    .collect()
+}
+
+fn overlaps_completely(a: &Assignment, b: &Assignment) -> bool {
+    a.contains(b) || b.contains(a)
+}
+
+fn overlaps(a: &Assignment, b: &Assignment) -> bool {
+    a.overlaps(b)
+}
+
+/// The sections assigned to any elf.
+pub fn coverage(assignments: &[(Assignment, Assignment)]) -> IntervalSet {
+    assignments.iter()
+    .flat_map(|&(a, b)| [a, b])
+    .collect()
+}
+
+/// The number of sections both elves of each pair are assigned to.
+pub fn overlap_sizes(assignments: &[(Assignment, Assignment)]) -> Vec<usize> {
+    assignments.iter()
+    .map(|(a, b)| a.intersection(b).map_or(0, |overlap| overlap.length()))
+    .collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<(Assignment, Assignment)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Assignment, Assignment)>, ParseError> {
+        read_shifts(input)
+    }
+
+    fn part1(assignments: &Vec<(Assignment, Assignment)>) -> usize {
+        assignments.iter()
+        .filter(|(a,b)| overlaps_completely(a,b))
+        .count()
+    }
+
+    fn part2(assignments: &Vec<(Assignment, Assignment)>) -> usize {
+        assignments.iter()
+        .filter(|(a,b)| overlaps(a,b))
+        .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part1(&input), 2);
+        assert_eq!(Day04::part2(&input), 4);
+    }
+
+    fn interval(start: i32, end: i32) -> Interval {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn overlapping_assignments() {
+        assert!(overlaps_completely(&interval(2, 8), &interval(3, 7)));
+        assert!(overlaps_completely(&interval(4, 6), &interval(6, 6)));
+        assert!(!overlaps_completely(&interval(5, 7), &interval(7, 9)));
+        assert!(overlaps(&interval(5, 7), &interval(7, 9)));
+        assert!(overlaps(&interval(6, 6), &interval(4, 6)));
+        assert!(!overlaps(&interval(2, 4), &interval(6, 8)));
+        assert!(!overlaps(&interval(2, 3), &interval(4, 5)));
+    }
+
+    #[test]
+    fn measures_sections() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(coverage(&input).to_string(), "2-9");
+        assert_eq!(overlap_sizes(&input), [0, 0, 1, 5, 1, 3]);
+    }
+}
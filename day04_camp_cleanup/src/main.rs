@@ -1,50 +1,90 @@
-use std::{fs::{File}, io::{self, BufRead}};
+use aoc_core::cli::{self, Args, DayOption, Format};
+use day04_camp_cleanup::{
+    coverage,
+    interval::Interval,
+    overlap_sizes,
+    sweep::{count_overlapping_pairs, elves, label, overlapping_pairs, peak},
+    Day04
+};
+use serde_json::json;
 
-type Assignment = (i32, i32);
+aoc_core::install_profiler!();
 
-fn parse_line(line: &str) -> (Assignment, Assignment) {
-    let assignments: Vec<Assignment> = line.split(",")
-    .map(|s| s.split_once("-").unwrap())
-    .map(|shift| (shift.0.parse().unwrap(), shift.1.parse().unwrap()))
-    .collect();
-    return (assignments[0], assignments[1]);
-}
-
-fn read_shifts(path: &str) -> Vec<(Assignment, Assignment)> {
-    let file = File::open(path).unwrap();
-    let reader = io::BufReader::new(file);
-    return reader.lines()
-    .map(|l| l.unwrap())
-    .map(|l| parse_line(&l))
-    .collect();
-
-}
+const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input/task_1.txt");
 
-fn overlaps_completely(a: &Assignment, b: &Assignment) -> bool {
-    let (a1, a2) = a;
-    let (b1, b2) = b;
-    a1 <= b1 && a2 >= b2 || b1 <= a1 && b2 >= a2
-}
-
-fn overlaps(a: &Assignment, b: &Assignment) -> bool {
-    let (a1, a2) = a;
-    let (b1, b2) = b;
-    a1 <= b1 && b1 <= a2 || b1 <= a1  && a1 <= b2
-}
+const OPTIONS: &[DayOption] = &[
+    DayOption { name: "coverage", value: None, help: "count the sections assigned to any elf and list those assigned to nobody" },
+    DayOption { name: "range", value: Some("<start-end>"), help: "look for unassigned sections in this range instead of between the first and last assigned one" },
+    DayOption { name: "overlaps", value: None, help: "list how many sections the elves of each pair share" },
+    DayOption { name: "sweep", value: None, help: "count the elves of any lines sharing sections and find the sections with the most elves" },
+    DayOption { name: "pairs", value: None, help: "list every two elves of any lines that share a section" }
+];
 
 fn main() {
-    let input = "./inputs/task_1.txt";
-    let assignments = read_shifts(input);
-
-    let overlap_count = assignments.iter()
-    .filter(|(a,b)| overlaps_completely(a,b))
-    .count();
-    println!("[Task 1] Number of overlapping assignments: {}", overlap_count);
-
-    let overlap_count = assignments.iter()
-    .filter(|(a,b)| overlaps(a,b))
-    .count();
-    println!("[Task 2] Number of partially overlapping assignments: {}", overlap_count);
-
-
+    let args = Args::from_env_with(OPTIONS);
+    let range = args.option::<Interval>("range");
+    let assignments = cli::run_with::<Day04>(
+        &args,
+        INPUT,
+        ["Number of overlapping assignments", "Number of partially overlapping assignments"],
+        None
+    );
+    if args.flag("coverage") {
+        let covered = coverage(&assignments);
+        let span = covered.intervals().first().zip(covered.intervals().last());
+        let range = range.or(span.map(|(first, last)| Interval { start: first.start, end: last.end }));
+        let gaps = range.map(|range| covered.gaps(range)).unwrap_or_default();
+        let gap_list: Vec<String> = gaps.intervals().iter().map(Interval::to_string).collect();
+        match args.format {
+            Format::Json => println!("{}", json!({
+                "day": 4,
+                "covered": covered.len(),
+                "range": range.map(|range| range.to_string()),
+                "unassigned": gaps.len(),
+                "gaps": gap_list
+            })),
+            Format::Text => {
+                println!("Sections assigned: {} in {}", covered.len(), covered);
+                match range {
+                    Some(range) if gaps.is_empty() => println!("Sections assigned to nobody in {}: none", range),
+                    Some(range) => println!("Sections assigned to nobody in {}: {} in {}", range, gaps.len(), gaps),
+                    None => {}
+                }
+            }
+        }
+    }
+    if args.flag("overlaps") {
+        let sizes = overlap_sizes(&assignments);
+        match args.format {
+            Format::Json => println!("{}", json!({ "day": 4, "overlaps": sizes })),
+            Format::Text => {
+                println!("{:>6} {:>9} {:>9} {:>8}", "Line", "First", "Second", "Overlap");
+                for (i, ((a, b), size)) in assignments.iter().zip(&sizes).enumerate() {
+                    println!("{:>6} {:>9} {:>9} {:>8}", i + 1, a.to_string(), b.to_string(), size);
+                }
+            }
+        }
+    }
+    if args.flag("sweep") {
+        let elves = elves(&assignments);
+        let pairs = count_overlapping_pairs(&elves);
+        let peak = peak(&elves);
+        let sections: Vec<String> = peak.sections.intervals().iter().map(Interval::to_string).collect();
+        match args.format {
+            Format::Json => println!("{}", json!({ "day": 4, "overlapping_pairs": pairs, "peak": peak.elves, "sections": sections })),
+            Format::Text => {
+                println!("Pairs of elves sharing sections: {}", pairs);
+                println!("Most elves on one section: {} in {}", peak.elves, peak.sections);
+            }
+        }
+    }
+    if args.flag("pairs") {
+        let elves = elves(&assignments);
+        for (a, b) in overlapping_pairs(&elves) {
+            match args.format {
+                Format::Json => println!("{}", json!({ "day": 4, "elves": [label(a), label(b)], "sections": [elves[a].to_string(), elves[b].to_string()] })),
+                Format::Text => println!("{:>8} {:>9} {:>8} {:>9}", label(a), elves[a].to_string(), label(b), elves[b].to_string())
+            }
+        }
+    }
 }
@@ -0,0 +1,126 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{
+    interval::{Interval, IntervalSet},
+    Assignment
+};
+
+/// The assignments of all elves, the two elves of line `n` being `2n - 2`
+/// and `2n - 1`.
+pub fn elves(assignments: &[(Assignment, Assignment)]) -> Vec<Interval> {
+    assignments.iter()
+    .flat_map(|&(a, b)| [a, b])
+    .collect()
+}
+
+/// The line of an elf and whether it is the first or second elf of it, as
+/// e.g. `3:2`.
+pub fn label(elf: usize) -> String {
+    format!("{}:{}", elf / 2 + 1, elf % 2 + 1)
+}
+
+/// The indices of all pairs of elves assigned to a common section, lower
+/// index first and ordered. The elves are swept by their first section,
+/// keeping those still assigned to it in a heap, so each pair costs a
+/// constant besides sorting.
+pub fn overlapping_pairs(elves: &[Interval]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..elves.len()).collect();
+    order.sort_unstable_by_key(|&elf| elves[elf].start);
+    let mut active: BinaryHeap<Reverse<(i32, usize)>> = BinaryHeap::new();
+    let mut pairs = Vec::new();
+    for elf in order {
+        let start = elves[elf].start;
+        while active.peek().is_some_and(|Reverse((end, _))| *end < start) {
+            active.pop();
+        }
+        pairs.extend(active.iter().map(|&Reverse((_, other))| (other.min(elf), other.max(elf))));
+        active.push(Reverse((elves[elf].end, elf)));
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+/// The number of pairs of elves assigned to a common section, without
+/// listing them: all pairs but those where one elf starts after the other
+/// ends.
+pub fn count_overlapping_pairs(elves: &[Interval]) -> u64 {
+    let mut starts: Vec<i32> = elves.iter().map(|elf| elf.start).collect();
+    starts.sort_unstable();
+    let n = elves.len() as u64;
+    let apart: u64 = elves.iter()
+    .map(|elf| (starts.len() - starts.partition_point(|&start| start <= elf.end)) as u64)
+    .sum();
+    n * n.saturating_sub(1) / 2 - apart
+}
+
+/// The most elves assigned to a single section and the sections with that
+/// many elves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Peak {
+    pub elves: usize,
+    pub sections: IntervalSet
+}
+
+/// Sweeps over the first and one past the last section of each elf,
+/// counting the elves in between.
+pub fn peak(elves: &[Interval]) -> Peak {
+    let mut events: Vec<(i64, i64)> = elves.iter()
+    .flat_map(|elf| [(elf.start as i64, 1), (elf.end as i64 + 1, -1)])
+    .collect();
+    events.sort_unstable();
+    let (mut depth, mut most) = (0, 0);
+    let mut sections = Vec::new();
+    let mut i = 0;
+    while i < events.len() {
+        let position = events[i].0;
+        while i < events.len() && events[i].0 == position {
+            depth += events[i].1;
+            i += 1;
+        }
+        let Some(&(next, _)) = events.get(i) else { break };
+        if depth > most {
+            most = depth;
+            sections.clear();
+        }
+        if depth == most && depth > 0 {
+            sections.push(Interval { start: position as i32, end: (next - 1) as i32 });
+        }
+    }
+    Peak { elves: most as usize, sections: sections.into_iter().collect() }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::{generate::generate, Day04};
+
+    #[test]
+    fn finds_overlaps_across_lines() {
+        let elves = elves(&Day04::parse(include_str!("../input/task_0.txt")).unwrap());
+        let pairs = overlapping_pairs(&elves);
+        assert_eq!(pairs[..3], [(0, 2), (0, 3), (0, 6)]);
+        assert_eq!(count_overlapping_pairs(&elves), pairs.len() as u64);
+        assert_eq!(label(7), "4:2");
+        let peak = peak(&elves);
+        assert_eq!((peak.elves, peak.sections.to_string().as_str()), (8, "6-6"));
+    }
+
+    #[test]
+    fn matches_quadratic_comparison() {
+        check(generate, 50, 40, |input| {
+            let elves = elves(&Day04::parse(input).unwrap());
+            let mut pairs = Vec::new();
+            for i in 0..elves.len() {
+                pairs.extend((i + 1..elves.len()).filter(|&j| elves[i].overlaps(&elves[j])).map(|j| (i, j)));
+            }
+            assert_eq!(overlapping_pairs(&elves), pairs);
+            assert_eq!(count_overlapping_pairs(&elves), pairs.len() as u64);
+            let depth = |section| elves.iter().filter(|elf| elf.contains_section(section)).count();
+            let most = (1..=99).map(depth).max().unwrap();
+            let sections: IntervalSet = (1..=99).filter(|&s| depth(s) == most).map(|s| Interval { start: s, end: s }).collect();
+            assert_eq!(peak(&elves), Peak { elves: most, sections });
+        });
+    }
+}
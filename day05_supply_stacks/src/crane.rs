@@ -0,0 +1,145 @@
+use crate::Stack;
+
+/// A model of crane, which decides how crates are lifted from one stack
+/// to another.
+pub trait Crane {
+    fn name(&self) -> String;
+
+    /// Moves the top `amount` crates of stack `from` onto stack `to`, or
+    /// fails without touching the stacks if `from` holds fewer crates.
+    fn lift(&self, stacks: &mut [Stack<char>], amount: usize, from: usize, to: usize) -> Result<(), String>;
+}
+
+/// Checks that stack `from` holds at least `amount` crates.
+fn check(stacks: &[Stack<char>], amount: usize, from: usize) -> Result<(), String> {
+    match stacks[from].len() {
+        height if height < amount => Err(format!("cannot move {} crates from stack {} holding {}", amount, from + 1, height)),
+        _ => Ok(())
+    }
+}
+
+/// Moves one crate at a time, reversing their order.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        "CrateMover 9000".to_string()
+    }
+
+    fn lift(&self, stacks: &mut [Stack<char>], amount: usize, from: usize, to: usize) -> Result<(), String> {
+        check(stacks, amount, from)?;
+        for _ in 0..amount {
+            let item = stacks[from].pop().unwrap();
+            stacks[to].push(item);
+        }
+        Ok(())
+    }
+}
+
+/// Moves all crates at once, keeping their order.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        "CrateMover 9001".to_string()
+    }
+
+    fn lift(&self, stacks: &mut [Stack<char>], amount: usize, from: usize, to: usize) -> Result<(), String> {
+        check(stacks, amount, from)?;
+        let rest = stacks[from].len() - amount;
+        let mut load = stacks[from].split_off(rest);
+        stacks[to].append(&mut load);
+        Ok(())
+    }
+}
+
+/// A CrateMover 9001 that lifts at most `capacity` crates at once, moving
+/// more in several batches.
+pub struct Limited {
+    capacity: usize
+}
+
+impl Limited {
+    pub fn new(capacity: usize) -> Result<Limited, String> {
+        match capacity {
+            0 => Err("a crane must lift at least one crate".to_string()),
+            _ => Ok(Limited { capacity })
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl Crane for Limited {
+    fn name(&self) -> String {
+        format!("CrateMover 9001 lifting up to {} crates", self.capacity)
+    }
+
+    fn lift(&self, stacks: &mut [Stack<char>], amount: usize, from: usize, to: usize) -> Result<(), String> {
+        check(stacks, amount, from)?;
+        let mut left = amount;
+        while left > 0 {
+            let batch = left.min(self.capacity);
+            CrateMover9001.lift(stacks, batch, from, to)?;
+            left -= batch;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! models {
+    () => { "9000, 9001 or 9001:<capacity>" };
+}
+
+/// The models that [`model`] knows.
+pub const MODELS: &str = models!();
+
+/// The help text of an option selecting a crane.
+pub const HELP: &str = concat!("also move the crates with a crane model: ", models!());
+
+/// The crane of a model, `9000`, `9001` or `9001:<capacity>` for a
+/// CrateMover 9001 lifting at most `capacity` crates at once.
+pub fn model(name: &str) -> Result<Box<dyn Crane>, String> {
+    match name.split_once(':') {
+        None if name == "9000" => Ok(Box::new(CrateMover9000)),
+        None if name == "9001" => Ok(Box::new(CrateMover9001)),
+        Some(("9001", capacity)) => {
+            let capacity = capacity.parse().map_err(|_| format!("invalid capacity '{}', expected a positive number", capacity))?;
+            Ok(Box::new(Limited::new(capacity)?))
+        },
+        _ => Err(format!("unknown crane '{}', expected {}", name, MODELS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lift(crane: &dyn Crane, amount: usize) -> Vec<Stack<char>> {
+        let mut stacks = vec!["ABCDE".chars().collect(), vec!['Z']];
+        crane.lift(&mut stacks, amount, 0, 1).unwrap();
+        stacks
+    }
+
+    #[test]
+    fn lifts_by_model() {
+        assert_eq!(lift(&CrateMover9000, 3)[1], ['Z', 'E', 'D', 'C']);
+        assert_eq!(lift(&CrateMover9001, 3)[1], ['Z', 'C', 'D', 'E']);
+        assert_eq!(lift(&Limited::new(2).unwrap(), 5), [vec![], vec!['Z', 'D', 'E', 'B', 'C', 'A']]);
+        assert_eq!(lift(&Limited::new(1).unwrap(), 4), lift(&CrateMover9000, 4));
+        assert_eq!(model("9001:3").unwrap().name(), "CrateMover 9001 lifting up to 3 crates");
+        assert_eq!(model("9002").err().unwrap(), "unknown crane '9002', expected 9000, 9001 or 9001:<capacity>");
+        assert!(model("9001:0").is_err() && Limited::new(0).is_err());
+    }
+
+    #[test]
+    fn refuses_to_lift_missing_crates() {
+        let mut stacks = vec![vec!['A'], vec![]];
+        for crane in [&CrateMover9000 as &dyn Crane, &CrateMover9001, &Limited::new(1).unwrap()] {
+            assert_eq!(crane.lift(&mut stacks, 2, 0, 0), Err("cannot move 2 crates from stack 1 holding 1".to_string()));
+            assert_eq!(stacks, [vec!['A'], vec![]]);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use aoc_core::random::{Rng, StdRng};
+
+/// Up to nine stacks of up to `size` crates, followed by `2 * size` moves.
+/// Every move leaves at least one crate behind, so no stack ends up empty.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut stacks: Vec<Vec<char>> = (0..rng.gen_range(2..=9))
+    .map(|_| (0..rng.gen_range(1..=size)).map(|_| rng.gen_range('A'..='Z')).collect())
+    .collect();
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    let mut input = String::new();
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks.iter()
+        .map(|stack| stack.get(level).map_or("   ".to_string(), |c| format!("[{}]", c)))
+        .collect();
+        input += &format!("{}\n", row.join(" "));
+    }
+    let labels: Vec<String> = (1..=stacks.len()).map(|i| format!(" {} ", i)).collect();
+    input += &format!("{}\n\n", labels.join(" "));
+    for _ in 0..2 * size {
+        let sources: Vec<usize> = (0..stacks.len()).filter(|&i| stacks[i].len() > 1).collect();
+        if sources.is_empty() {
+            break;
+        }
+        let from = sources[rng.gen_range(0..sources.len())];
+        let to = (from + rng.gen_range(1..stacks.len())) % stacks.len();
+        let amount = rng.gen_range(1..stacks[from].len());
+        let remaining = stacks[from].len() - amount;
+        let load = stacks[from].split_off(remaining);
+        stacks[to].extend(load);
+        input += &format!("move {} from {} to {}\n", amount, from + 1, to + 1);
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day05;
+
+    /// Moves the crates of the generated input, either one at a time or all
+    /// at once, and returns the top crates.
+    fn reference(input: &str, at_once: bool) -> String {
+        let (drawing, moves) = input.split_once("\n\n").unwrap();
+        let mut rows: Vec<&str> = drawing.lines().collect();
+        let labels = rows.pop().unwrap();
+        let mut stacks = vec![Vec::new(); labels.split_whitespace().count()];
+        for row in rows.iter().rev() {
+            for (i, stack) in stacks.iter_mut().enumerate() {
+                match row.as_bytes().get(4 * i + 1) {
+                    Some(&c) if c != b' ' => stack.push(c as char),
+                    _ => {}
+                }
+            }
+        }
+        for line in moves.lines() {
+            let numbers: Vec<usize> = line.split(' ').filter_map(|t| t.parse().ok()).collect();
+            let (amount, from, to) = (numbers[0], numbers[1] - 1, numbers[2] - 1);
+            let remaining = stacks[from].len() - amount;
+            let mut load = stacks[from].split_off(remaining);
+            if !at_once {
+                load.reverse();
+            }
+            stacks[to].extend(load);
+        }
+        stacks.iter().map(|stack| stack.last().unwrap()).collect()
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 20, |input| {
+            let supplies = Day05::parse(input).unwrap();
+            assert_eq!(Day05::part1(&supplies), reference(input, false));
+            assert_eq!(Day05::part2(&supplies), reference(input, true));
+        });
+    }
+}
@@ -0,0 +1,169 @@
+pub mod crane;
+pub mod generate;
+
+use aoc_core::{parse::{next, number, split_once}, ParseError, Solution};
+use crane::{Crane, CrateMover9000, CrateMover9001};
+use regex::Regex;
+
+type Stack<T> = Vec<T>;
+
+#[derive(Clone)]
+struct Command {
+    amount: usize,
+    from: usize,
+    to: usize
+}
+
+fn keyword<'a>(tokens: &mut impl Iterator<Item = &'a str>, line: &'a str, word: &str) -> Result<(), ParseError> {
+    let token = next(tokens, line, &format!("`{}`", word))?;
+    if token != word {
+        return Err(ParseError::at(token, format!("expected `{}`", word)));
+    }
+    Ok(())
+}
+
+fn stack_index<'a>(tokens: &mut impl Iterator<Item = &'a str>, line: &'a str, num_stacks: usize) -> Result<usize, ParseError> {
+    let token = next(tokens, line, "a stack number")?;
+    let index: usize = number(token)?;
+    if index < 1 || index > num_stacks {
+        return Err(ParseError::at(token, format!("stack number out of range 1..={}", num_stacks)));
+    }
+    Ok(index - 1)
+}
+
+fn parse_command(line: &str, num_stacks: usize) -> Result<Command, ParseError> {
+    let mut tokens = line.split_whitespace();
+    keyword(&mut tokens, line, "move")?;
+    let amount: usize = number(next(&mut tokens, line, "the number of crates")?)?;
+    keyword(&mut tokens, line, "from")?;
+    let from = stack_index(&mut tokens, line, num_stacks)?;
+    keyword(&mut tokens, line, "to")?;
+    let to = stack_index(&mut tokens, line, num_stacks)?;
+    Ok(Command { amount, from, to })
+}
+
+/// Reads the moves, checking that each stack holds enough crates for them.
+fn parse_commands(input: &str, stacks: &[Stack<char>]) -> Result<Vec<Command>, ParseError> {
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    input.lines()
+    .filter(|line| !line.is_empty())
+    .map(|line| {
+        let command = parse_command(line, heights.len())?;
+        if heights[command.from] < command.amount {
+            let message = format!("cannot move {} crates from stack {} holding {}", command.amount, command.from + 1, heights[command.from]);
+            return Err(ParseError::at(line, message));
+        }
+        heights[command.from] -= command.amount;
+        heights[command.to] += command.amount;
+        Ok(command)
+    })
+    .collect()
+}
+
+fn parse_stacks(input: &str) -> Result<Vec<Stack<char>>, ParseError> {
+    let re = Regex::new(r"[0-9]+").unwrap();
+    let labels = input.lines().last().unwrap_or(input);
+    let num_stacks = re.find_iter(labels).count();
+    if num_stacks == 0 {
+        return Err(ParseError::at(labels, "expected a line of stack numbers"));
+    }
+    let mut stacks: Vec<Stack<char>> =  (0..num_stacks).map(|_| Vec::new()).collect();
+    let max_size = input.lines().count() - 1;
+    for line in input.lines().take(max_size) {
+       for (i, start) in (0..line.len()).step_by(4).enumerate() {
+        let elem = line.get(start..line.len().min(start + 3)).unwrap_or("").trim();
+        if !elem.is_empty() {
+            let item = elem.strip_prefix('[').and_then(|e| e.strip_suffix(']')).unwrap_or("");
+            if item.chars().count() != 1 {
+                return Err(ParseError::at(elem, "invalid crate, expected `[X]`"));
+            }
+            if i >= num_stacks {
+                return Err(ParseError::at(elem, format!("crate outside of the {} numbered stacks", num_stacks)));
+            }
+            stacks[i].insert(0, item.chars().next().unwrap());
+        }
+       }
+    }
+    Ok(stacks)
+}
+
+pub struct Supplies {
+    stacks: Vec<Stack<char>>,
+    commands: Vec<Command>
+}
+
+fn parse_input(input: &str) -> Result<Supplies, ParseError> {
+    let (stacks, moves) = split_once(input, "\n\n")?;
+    let stacks = parse_stacks(stacks)?;
+    let commands = parse_commands(moves, &stacks)?;
+    Ok(Supplies { stacks, commands })
+}
+
+fn apply(commands: &[Command], stacks: &mut [Stack<char>], crane: &dyn Crane) -> Result<(), String> {
+    for command in commands {
+        crane.lift(stacks, command.amount, command.from, command.to)?;
+    }
+    Ok(())
+}
+
+/// The crates on top of each stack after `crane` moved them, with a space
+/// for each empty stack.
+pub fn top_elements(supplies: &Supplies, crane: &dyn Crane) -> Result<String, String> {
+    let mut stacks = supplies.stacks.clone();
+    apply(&supplies.commands, &mut stacks, crane)?;
+    let top = stacks.iter()
+    .map(|s| s.last().copied().unwrap_or(' '))
+    .collect();
+    Ok(top)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Supplies;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Supplies, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(supplies: &Supplies) -> String {
+        top_elements(supplies, &CrateMover9000).expect("the moves are checked when parsing")
+    }
+
+    fn part2(supplies: &Supplies) -> String {
+        top_elements(supplies, &CrateMover9001).expect("the moves are checked when parsing")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part1(&input), "CMZ");
+        assert_eq!(Day05::part2(&input), "MCD");
+        assert_eq!(top_elements(&input, &crane::Limited::new(2).unwrap()).unwrap(), "MCZ");
+    }
+
+    #[test]
+    fn keeps_empty_stacks() {
+        let input = Day05::parse(&(EXAMPLE.trim_end().to_string() + "\nmove 1 from 1 to 2\n")).unwrap();
+        assert_eq!(Day05::part1(&input), " CZ");
+        let input = EXAMPLE.replacen("move 3 from 1 to 3", "move 4 from 1 to 3", 1);
+        assert_eq!(Day05::parse(&input).err().unwrap().message, "cannot move 4 crates from stack 1 holding 3");
+    }
+
+    #[test]
+    fn rejects_negative_amounts() {
+        let input = EXAMPLE.replacen("move 1 from 2 to 1", "move -1 from 2 to 1", 1);
+        assert_eq!(Day05::parse(&input).err().unwrap().message, "invalid number");
+    }
+}
@@ -0,0 +1,44 @@
+use aoc_core::random::{Rng, SliceRandom, StdRng};
+
+/// A signal of about `10 * size` letters. The body draws from a random
+/// number of letters, so it may or may not contain markers, and it always
+/// ends in fourteen different letters.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let letters = rng.gen_range(2..=26);
+    let mut signal: String = (0..10 * size)
+    .map(|_| (b'a' + rng.gen_range(0..letters)) as char)
+    .collect();
+    let mut alphabet: Vec<char> = ('a'..='z').collect();
+    alphabet.shuffle(rng);
+    signal.extend(&alphabet[..14]);
+    signal + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day06;
+
+    /// The number of characters up to the end of the first `length` distinct
+    /// characters in a row.
+    fn reference(signal: &str, length: usize) -> usize {
+        let signal = signal.as_bytes();
+        (length..=signal.len())
+        .find(|&end| {
+            let window = &signal[end - length..end];
+            (0..length).all(|i| (i + 1..length).all(|j| window[i] != window[j]))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 50, |input| {
+            let signal = Day06::parse(input).unwrap();
+            assert_eq!(Day06::part1(&signal), reference(input.trim_end(), 4));
+            assert_eq!(Day06::part2(&signal), reference(input.trim_end(), 14));
+        });
+    }
+}
@@ -0,0 +1,64 @@
+pub mod generate;
+
+use std::collections::HashSet;
+
+use aoc_core::{ParseError, Solution};
+
+fn process_signal(signal: &str, window_size: usize) -> usize {
+    let signals: Vec<char> = signal.chars().collect();
+    signals.windows(window_size)
+    .enumerate()
+    .find(|(_, window)| window.iter().collect::<HashSet<&char>>().len() == window_size)
+    .map(|(i, _)| i+window_size)
+    .unwrap()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(signal: &String) -> usize {
+        process_signal(signal, 4)
+    }
+
+    fn part2(signal: &String) -> usize {
+        process_signal(signal, 14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part1(&input), 7);
+        assert_eq!(Day06::part2(&input), 19);
+    }
+
+    #[test]
+    fn markers() {
+        let signals = [
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26)
+        ];
+        for (signal, packet, message) in signals {
+            assert_eq!(process_signal(signal, 4), packet);
+            assert_eq!(process_signal(signal, 14), message);
+        }
+    }
+}
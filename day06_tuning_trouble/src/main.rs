@@ -1,18 +1,11 @@
-use std::{fs::read_to_string, collections::HashSet};
+use aoc_core::cli;
+use day06_tuning_trouble::Day06;
 
-fn process_signal(signal: &str, window_size: usize) -> usize {
-    let signals: Vec<char> = signal.chars().collect();
-    signals.windows(window_size)
-    .enumerate()
-    .find(|(_, window)| window.iter().collect::<HashSet<&char>>().len() == window_size)
-    .map(|(i, _)| i+window_size)
-    .unwrap()
-}
+aoc_core::install_profiler!();
 
 fn main() {
-    let input = read_to_string("./input/task_1.txt").unwrap();
-    let task_1_solution = process_signal(input.as_str(), 4);
-    println!("[Task 1] Start of packet at: {}", task_1_solution);
-    let task_2_solution = process_signal(input.as_str(), 14);
-    println!("[Task 2] Start of message at: {}", task_2_solution);
-}
\ No newline at end of file
+    cli::run::<Day06>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/input/task_1.txt"),
+        ["Start of packet at", "Start of message at"]
+    );
+}
@@ -0,0 +1,104 @@
+use aoc_core::random::{Rng, StdRng};
+
+struct Directory {
+    files: Vec<usize>,
+    children: Vec<usize>
+}
+
+/// Lists `directory` and walks into its children. Some children are entered
+/// and listed a second time, which must not count their files twice.
+fn explore(rng: &mut StdRng, directories: &[Directory], directory: usize, log: &mut String) {
+    *log += "$ ls\n";
+    for &child in &directories[directory].children {
+        *log += &format!("dir d{}\n", child);
+    }
+    for (i, size) in directories[directory].files.iter().enumerate() {
+        *log += &format!("{} f{}.txt\n", size, i);
+    }
+    for &child in &directories[directory].children {
+        *log += &format!("$ cd d{}\n", child);
+        explore(rng, directories, child, log);
+        *log += "$ cd ..\n";
+        if rng.gen_bool(0.1) {
+            *log += &format!("$ cd d{}\n$ ls\n", child);
+            for (i, size) in directories[child].files.iter().enumerate() {
+                *log += &format!("{} f{}.txt\n", size, i);
+            }
+            *log += "$ cd ..\n";
+        }
+    }
+}
+
+/// The terminal log of exploring a random tree of `size` directories. The
+/// root gets padded so that between 40 and 70 million of the disk are used,
+/// as the second part needs.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let max_file = 50_000.min(30_000_000 / (3 * size));
+    let mut directories: Vec<Directory> = (0..size)
+    .map(|_| Directory {
+        files: (0..rng.gen_range(0..=3)).map(|_| rng.gen_range(1..=max_file)).collect(),
+        children: Vec::new()
+    })
+    .collect();
+    for child in 1..size {
+        let parent = rng.gen_range(0..child);
+        directories[parent].children.push(child);
+    }
+    let used: usize = directories.iter().flat_map(|d| &d.files).sum();
+    directories[0].files.push(rng.gen_range(40_000_001..=70_000_000) - used);
+    let mut log = String::from("$ cd /\n");
+    explore(rng, &directories, 0, &mut log);
+    log
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day07;
+
+    /// The total size of every directory, keyed by its path.
+    fn reference_sizes(log: &str) -> HashMap<Vec<&str>, usize> {
+        let mut path: Vec<&str> = Vec::new();
+        let mut sizes = HashMap::from([(Vec::new(), 0)]);
+        let mut seen = HashSet::new();
+        for line in log.lines() {
+            match line.split(' ').collect::<Vec<_>>()[..] {
+                ["$", "cd", "/"] => path.clear(),
+                ["$", "cd", ".."] => drop(path.pop()),
+                ["$", "cd", name] => {
+                    path.push(name);
+                    sizes.entry(path.clone()).or_insert(0);
+                },
+                ["$", "ls"] | ["dir", _] => {},
+                [size, name] => {
+                    let mut file = path.clone();
+                    file.push(name);
+                    if seen.insert(file) {
+                        for end in 0..=path.len() {
+                            *sizes.get_mut(&path[..end]).unwrap() += size.parse::<usize>().unwrap();
+                        }
+                    }
+                },
+                _ => panic!("unexpected line {}", line)
+            }
+        }
+        sizes
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 30, |input| {
+            let filesystem = Day07::parse(input).unwrap();
+            let sizes = reference_sizes(input);
+            let small: usize = sizes.values().filter(|&&s| s <= 100000).sum();
+            let needed = sizes[&Vec::new()] - 40_000_000;
+            let smallest = sizes.values().filter(|&&s| s >= needed).min().copied();
+            assert_eq!(Day07::part1(&filesystem), small);
+            assert_eq!(Day07::part2(&filesystem), smallest.unwrap());
+        });
+    }
+}
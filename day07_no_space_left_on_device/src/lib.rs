@@ -0,0 +1,183 @@
+pub mod generate;
+
+use aoc_core::{parse::{number, prefix, split_once}, ParseError, Solution};
+
+struct Directory {
+    name: String,
+    files: Vec<File>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    total_size: usize
+}
+
+#[derive(PartialEq)]
+struct File {
+    name: String,
+    size: usize
+}
+
+impl File {
+    fn parse(line: &str) -> Result<File, ParseError> {
+        let (size, name) = split_once(line, " ")?;
+        Ok(File { name: name.to_string(), size: number(size)? })
+    }
+}
+
+pub struct FileSystem {
+    curdir: usize,
+    directories: Vec<Directory>,
+}
+
+impl FileSystem{
+
+    fn new(root: Directory) -> FileSystem {
+        FileSystem { curdir: 0, directories: vec![root] }
+    }
+
+    pub fn change_dir(&mut self, dir: &str) {
+        match dir {
+            ".." => {
+                let curdir = &self.directories[self.curdir];
+                if let Some(parent) = curdir.parent {
+                   self.curdir = parent;
+                }
+            },
+            "/" => self.curdir = 0,
+            dir => {
+                let curdir = &self.directories[self.curdir];
+                for node in &curdir.children {
+                    if self.directories[*node].name == dir {
+                        self.curdir = *node;
+                        return;
+                    }
+                }
+                let idx = self.directories.len();
+                let new_dir = Directory {
+                    name: dir.to_string(),
+                    children: Vec::new(),
+                    files: Vec::new(),
+                    parent: Option::Some(self.curdir),
+                    total_size: 0
+                };
+                self.directories.push(new_dir);
+                self.directories[self.curdir].children.push(idx);
+                self.curdir = idx;
+            }
+        }
+    }
+
+    pub fn list_dir(&mut self, contents: Vec<&str>) -> Result<(), ParseError> {
+        let curdir = &mut self.directories[self.curdir];
+        let files: Vec<File> = contents.iter()
+        .filter(|c| !c.starts_with("dir "))
+        .map(|l| File::parse(l))
+        .collect::<Result<_, _>>()?;
+        let mut size: usize = 0;
+        for file in files {
+            if !curdir.files.contains(&file) {
+                size += file.size;
+                curdir.files.push(file);
+            }
+        }
+        curdir.total_size += size;
+        let mut curdir_idx = curdir.parent;
+        while curdir_idx.is_some()  {
+            let curdir = &mut self.directories[curdir_idx.unwrap()];
+            curdir.total_size += size;
+            curdir_idx = curdir.parent;
+        }
+        Ok(())
+    }
+
+    fn apply_command(&mut self, command: &str, contents: Vec<&str>) -> Result<(), ParseError> {
+        if command.starts_with("cd") {
+            let (_, dir) = split_once(command, " ")?;
+            self.change_dir(dir);
+            Ok(())
+        } else if command == "ls" {
+            self.list_dir(contents)
+        } else {
+            Err(ParseError::at(command, "invalid command, expected `cd` or `ls`"))
+        }
+    }
+}
+
+fn apply_log(log: &str, filesystem: &mut FileSystem) -> Result<(), ParseError> {
+    let lines: Vec<&str> = log.lines().collect();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let command = prefix(line, "$ ")?;
+        let next_cmd_index = lines[i+1..lines.len()].iter()
+        .position(|l| l.starts_with('$'))
+        .map(|a| a + i + 1).unwrap_or(lines.len());
+        let output = if next_cmd_index > i + 1 {
+            lines[i+1..next_cmd_index].to_vec()
+        } else {
+            Vec::new()
+        };
+        filesystem.apply_command(command, output)?;
+        i = next_cmd_index;
+    }
+    Ok(())
+}
+
+
+fn build_filesystem(input: &str) -> Result<FileSystem, ParseError> {
+    let root = Directory {
+        name: String::from("/"),
+        files: Vec::new(),
+        children: Vec::new(),
+        parent: Option::None,
+        total_size: 0
+    };
+
+    let mut filesystem = FileSystem::new(root);
+    apply_log(input, &mut filesystem)?;
+    Ok(filesystem)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = FileSystem;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<FileSystem, ParseError> {
+        build_filesystem(input)
+    }
+
+    fn part1(filesystem: &FileSystem) -> usize {
+        filesystem.directories.iter()
+        .map(|d| d.total_size)
+        .filter(|s| *s <= 100000)
+        .sum()
+    }
+
+    fn part2(filesystem: &FileSystem) -> usize {
+        let available = 70000000 - filesystem.directories[0].total_size;
+        let to_be_freed = 30000000 - available;
+        filesystem.directories.iter()
+        .map(|dir| dir.total_size)
+        .filter(|&size| size >= to_be_freed)
+        .min()
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part1(&input), 95437);
+        assert_eq!(Day07::part2(&input), 24933642);
+    }
+}
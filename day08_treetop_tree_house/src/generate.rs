@@ -0,0 +1,60 @@
+use aoc_core::random::{Rng, StdRng};
+
+/// A forest of up to `size` by `size` trees. The heights draw from a random
+/// range of digits, so some forests have many trees of equal height.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let (width, height) = (rng.gen_range(1..=size), rng.gen_range(1..=size));
+    let tallest = rng.gen_range(0..=9);
+    (0..height)
+    .map(|_| (0..width).map(|_| char::from_digit(rng.gen_range(0..=tallest), 10).unwrap()).collect::<String>() + "\n")
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day08;
+
+    fn heights(input: &str) -> Vec<Vec<u32>> {
+        input.lines()
+        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .collect()
+    }
+
+    /// The trees in each direction from `(x, y)`, nearest first.
+    fn lines_of_sight(trees: &[Vec<u32>], x: usize, y: usize) -> [Vec<u32>; 4] {
+        [
+            (0..y).rev().map(|i| trees[i][x]).collect(),
+            (x + 1..trees[y].len()).map(|i| trees[y][i]).collect(),
+            (y + 1..trees.len()).map(|i| trees[i][x]).collect(),
+            (0..x).rev().map(|i| trees[y][i]).collect()
+        ]
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 20, |input| {
+            let map = Day08::parse(input).unwrap();
+            let trees = heights(input);
+            let mut visible = 0;
+            let mut best = 0;
+            for y in 0..trees.len() {
+                for x in 0..trees[y].len() {
+                    let height = trees[y][x];
+                    let lines = lines_of_sight(&trees, x, y);
+                    if lines.iter().any(|line| line.iter().all(|&other| other < height)) {
+                        visible += 1;
+                    }
+                    let score: usize = lines.iter()
+                    .map(|line| line.iter().position(|&other| other >= height).map_or(line.len(), |i| i + 1))
+                    .product();
+                    best = best.max(score);
+                }
+            }
+            assert_eq!(Day08::part1(&map), visible);
+            assert_eq!(Day08::part2(&map), best);
+        });
+    }
+}
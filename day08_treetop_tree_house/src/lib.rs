@@ -0,0 +1,121 @@
+pub mod generate;
+
+use aoc_core::{ParseError, Part, Solution};
+use aoc_grid::{image::{heat, Draw, Image}, Grid, Point, STEPS_4};
+
+type Map = Grid<i32>;
+
+fn load_map(input: &str) -> Result<Map, ParseError> {
+    Grid::parse(input, |c| {
+        c.to_digit(10)
+        .map(|height| height as i32)
+        .ok_or_else(|| "invalid tree height, expected a digit".to_string())
+    })
+}
+
+/// From how many of the four sides the tree at `position` can be seen.
+fn visible_sides(map: &Map, position: Point) -> usize {
+    STEPS_4.iter()
+    .filter(|&&step| map.ray(position, step).all(|(_, other)| *other < map[position]))
+    .count()
+}
+
+fn count_visible(map: &Map) -> usize {
+    map.positions()
+    .filter(|&position| visible_sides(map, position) > 0)
+    .count()
+}
+
+fn scenic_score(map: &Map, position: Point) -> usize {
+    let height = map[position];
+    STEPS_4.iter()
+    .map(|&step| {
+        let mut distance = 0;
+        for (_, &other) in map.ray(position, step) {
+            distance += 1;
+            if other >= height {
+                break;
+            }
+        }
+        distance
+    })
+    .product()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        load_map(input)
+    }
+
+    fn part1(map: &Map) -> usize {
+        count_visible(map)
+    }
+
+    fn part2(map: &Map) -> usize {
+        map.positions()
+        .map(|position| scenic_score(map, position))
+        .max()
+        .unwrap()
+    }
+}
+
+/// Heatmaps of the forest: from how many sides each tree is visible, or how
+/// scenic it is, relative to the most scenic tree.
+impl Draw for Day08 {
+    fn draw(map: &Map, part: Part) -> Image {
+        let mut values = map.map(|_| 0);
+        for position in map.positions() {
+            values[position] = match part {
+                Part::One => visible_sides(map, position),
+                Part::Two => scenic_score(map, position)
+            };
+        }
+        // Scenic scores are products of distances, so they are compared on a
+        // logarithmic scale
+        let scale = |value: usize| match part {
+            Part::One => value as f64,
+            Part::Two => (value as f64).ln_1p()
+        };
+        let max = values.iter().map(|(_, &value)| scale(value)).fold(f64::MIN_POSITIVE, f64::max);
+        Image::from_grid(&values, 8, |&value| heat(scale(value) / max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part1(&input), 21);
+        assert_eq!(Day08::part2(&input), 8);
+    }
+
+    #[test]
+    fn scenic_scores() {
+        let map = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(scenic_score(&map, Point::new(2, 1)), 4);
+        assert_eq!(scenic_score(&map, Point::new(2, 3)), 8);
+        assert_eq!(scenic_score(&map, Point::new(0, 0)), 0);
+    }
+
+    #[test]
+    fn draws_heatmaps() {
+        let map = Day08::parse(EXAMPLE).unwrap();
+        let image = Day08::draw(&map, Part::Two);
+        assert_eq!((image.width(), image.height()), (40, 40));
+        assert_eq!(image.pixel(0, 0), heat(0.0));
+        assert_eq!(image.pixel(2 * 8, 3 * 8), heat(1.0));
+    }
+}
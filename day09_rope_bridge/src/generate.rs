@@ -0,0 +1,54 @@
+use aoc_core::random::{Rng, StdRng};
+
+/// `size` moves of the head, including moves of length zero.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+    .map(|_| format!("{} {}\n", ['U', 'R', 'D', 'L'][rng.gen_range(0..4)], rng.gen_range(0..=12)))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day09;
+
+    /// Pulls a rope of `length` knots along the moves and counts the
+    /// positions its tail visits.
+    fn reference(input: &str, length: usize) -> usize {
+        let mut knots = vec![(0i32, 0i32); length];
+        let mut visited = HashSet::from([(0, 0)]);
+        for line in input.lines() {
+            let (direction, steps) = line.split_once(' ').unwrap();
+            let (dx, dy) = match direction {
+                "U" => (0, -1),
+                "R" => (1, 0),
+                "D" => (0, 1),
+                _ => (-1, 0)
+            };
+            for _ in 0..steps.parse().unwrap() {
+                knots[0] = (knots[0].0 + dx, knots[0].1 + dy);
+                for i in 1..length {
+                    let (x, y) = (knots[i - 1].0 - knots[i].0, knots[i - 1].1 - knots[i].1);
+                    if x.abs() == 2 || y.abs() == 2 {
+                        knots[i] = (knots[i].0 + x.signum(), knots[i].1 + y.signum());
+                    }
+                }
+                visited.insert(knots[length - 1]);
+            }
+        }
+        visited.len()
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 50, |input| {
+            let commands = Day09::parse(input).unwrap();
+            assert_eq!(Day09::part1(&commands), reference(input, 2));
+            assert_eq!(Day09::part2(&commands), reference(input, 10));
+        });
+    }
+}
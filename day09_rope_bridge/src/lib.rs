@@ -0,0 +1,162 @@
+pub mod generate;
+
+use std::collections::HashSet;
+
+use aoc_core::{parse::{number, split_once}, ParseError, Part, Solution};
+use aoc_grid::{
+    animation::{Animate, Animation, Color, Frame},
+    BoundingBox,
+    Direction,
+    Grid,
+    Point
+};
+
+fn parse_direction(dir: &str) -> Result<Direction, ParseError> {
+    match dir {
+        "U" => Ok(Direction::Up),
+        "R" => Ok(Direction::Right),
+        "D" => Ok(Direction::Down),
+        "L" => Ok(Direction::Left),
+        _ => Err(ParseError::at(dir, "invalid direction, expected one of U, R, D, L"))
+    }
+}
+
+type State = Vec<Point>;
+
+fn apply(state: &State, direction: &Direction) -> State {
+    let mut new_state = vec![state[0] + direction.step()];
+
+    for knot in state[1..state.len()].iter() {
+        let prev_knot = new_state.last().unwrap();
+        let dknot = if prev_knot.chebyshev(*knot) > 1 {
+            (*prev_knot - *knot).signum()
+        } else {
+            Point::ORIGIN
+        };
+        new_state.push(*knot + dknot);
+    }
+    new_state
+}
+
+fn run_system(x0: State, commands: &[(Direction, usize)]) -> Vec<State> {
+    let mut trajectory: Vec<State> = vec![x0];
+    for (direction, n) in commands {
+        for _ in 0..*n {
+            let x = apply(trajectory.last().unwrap(), direction);
+            trajectory.push(x);
+        }
+    }
+    trajectory
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Direction, usize)>, ParseError> {
+    input.lines()
+    .map(|l| split_once(l, " "))
+    .map(|line| {
+        let (d, n) = line?;
+        Ok((parse_direction(d)?, number(n)?))
+    })
+    .collect()
+}
+
+fn count_tail_positions(commands: &[(Direction, usize)], knots: usize) -> usize {
+    let trajectory = run_system(vec![Point::ORIGIN; knots], commands);
+    trajectory.iter()
+    .map(|s| *s.last().unwrap())
+    .collect::<HashSet<Point>>()
+    .len()
+}
+
+/// Moves the rope step by step over the area it ever covers, marking where
+/// the tail has been with `#`. The head is `H`, the other knots are numbered.
+fn animate_rope(commands: &[(Direction, usize)], knots: usize) -> Animation {
+    let trajectory = run_system(vec![Point::ORIGIN; knots], commands);
+    let bounds = BoundingBox::of(trajectory.iter().flatten().copied()).unwrap();
+    let mut visited = Grid::with_bounds(bounds, '.');
+    visited[Point::ORIGIN] = 's';
+    let grid = visited.clone();
+    let steps = trajectory.len() - 1;
+    let mut count = 1;
+    let mut previous: Vec<Point> = Vec::new();
+    let frames = trajectory.into_iter()
+    .enumerate()
+    .map(move |(step, state)| {
+        let tail = *state.last().unwrap();
+        if visited[tail] == '.' {
+            visited[tail] = '#';
+            count += 1;
+        }
+        let mut changes: Vec<(Point, char)> = previous.iter().map(|&knot| (knot, visited[knot])).collect();
+        for (i, &knot) in state.iter().enumerate().rev() {
+            changes.push((knot, if i == 0 { 'H' } else { char::from_digit(i as u32, 10).unwrap_or('T') }));
+        }
+        let frame = Frame { changes, focus: state[0], caption: format!("step {}/{}, the tail visited {} positions", step, steps, count) };
+        previous = state;
+        frame
+    });
+    Animation {
+        grid,
+        frames: Box::new(frames),
+        palette: &[('H', Color::BrightRed), ('#', Color::Cyan), ('s', Color::BrightGreen), ('.', Color::Grey)]
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<(Direction, usize)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Direction, usize)>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(commands: &Vec<(Direction, usize)>) -> usize {
+        count_tail_positions(commands, 2)
+    }
+
+    fn part2(commands: &Vec<(Direction, usize)>) -> usize {
+        count_tail_positions(commands, 10)
+    }
+}
+
+impl Animate for Day09 {
+    fn animate(commands: Vec<(Direction, usize)>, part: Part) -> Animation {
+        match part {
+            Part::One => animate_rope(&commands, 2),
+            Part::Two => animate_rope(&commands, 10)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part1(&input), 13);
+        assert_eq!(Day09::part2(&input), 1);
+    }
+
+    #[test]
+    fn larger_example() {
+        let commands = Day09::parse("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20").unwrap();
+        assert_eq!(Day09::part2(&commands), 36);
+    }
+
+    #[test]
+    fn animates_every_step() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        let frames: Vec<Frame> = Day09::animate(input, Part::One).frames.collect();
+        assert_eq!(frames.len(), 25);
+        assert_eq!(frames[24].focus, Point::new(2, -2));
+        assert_eq!(frames[24].caption, "step 24/24, the tail visited 13 positions");
+    }
+}
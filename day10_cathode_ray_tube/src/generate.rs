@@ -0,0 +1,59 @@
+use aoc_core::random::{Rng, StdRng};
+
+/// A program that runs for at least the 240 cycles of the screen, plus
+/// `size` further instructions.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut program = String::new();
+    let mut cycles = 0;
+    let mut remaining = size;
+    while cycles < 240 || remaining > 0 {
+        if rng.gen_bool(0.3) {
+            program += "noop\n";
+            cycles += 1;
+        } else {
+            program += &format!("addx {}\n", rng.gen_range(-15..=15));
+            cycles += 2;
+        }
+        remaining = remaining.saturating_sub(1);
+    }
+    program
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day10;
+
+    /// The value of the register during each cycle, starting with cycle 1.
+    fn register(input: &str) -> Vec<i32> {
+        let mut x = 1;
+        let mut during = Vec::new();
+        for line in input.lines() {
+            during.push(x);
+            if let Some(value) = line.strip_prefix("addx ") {
+                during.push(x);
+                x += value.parse::<i32>().unwrap();
+            }
+        }
+        during
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 100, |input| {
+            let instructions = Day10::parse(input).unwrap();
+            let during = register(input);
+            let strength: i32 = (1..=during.len() as i32)
+            .filter(|cycle| cycle % 40 == 20)
+            .map(|cycle| cycle * during[cycle as usize - 1])
+            .sum();
+            let screen: Vec<String> = (0..6)
+            .map(|row| (0..40).map(|column| if (during[row * 40 + column] - column as i32).abs() <= 1 { '#' } else { '.' }).collect())
+            .collect();
+            assert_eq!(Day10::part1(&instructions), strength);
+            assert_eq!(Day10::part2(&instructions).to_string(), screen.join("\n"));
+        });
+    }
+}
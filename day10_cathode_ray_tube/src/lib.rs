@@ -0,0 +1,153 @@
+pub mod generate;
+
+use aoc_core::{parse::{number, split_once}, ParseError, Part, Solution};
+use aoc_grid::{
+    animation::{Animate, Animation, Color, Frame},
+    image::{Draw, Image},
+    Grid,
+    Point
+};
+
+pub enum Instruction {
+    Noop,
+    Add(i32)
+}
+
+impl Instruction {
+    fn parse(string: &str) -> Result<Instruction, ParseError> {
+        if string == "noop" {
+            Ok(Instruction::Noop)
+        } else if string.starts_with("addx") {
+            let val: i32 = number(split_once(string, " ")?.1)?;
+            Ok(Instruction::Add(val))
+        } else {
+            Err(ParseError::at(string, "invalid instruction, expected `noop` or `addx`"))
+        }
+    }
+}
+
+fn process(instructions: &[Instruction], x: i32) -> Vec<i32>{
+    let mut cycles: Vec<i32> = vec![x];
+    let mut current_value = x;
+    for instruction in instructions{
+        if let Instruction::Noop = instruction {
+            cycles.push(current_value)
+        } else if let Instruction::Add(value) = instruction {
+            cycles.push(current_value);
+            cycles.push(current_value);
+            current_value += *value;
+        }
+    }
+    cycles
+}
+
+fn draw(register_values: Vec<i32>) -> Grid<char> {
+    let mut screen = Grid::new(40, 6, '.');
+    for pixel in screen.positions().collect::<Vec<_>>() {
+        let value = register_values[(pixel.x + pixel.y * 40 + 1) as usize];
+        if (value - pixel.x).abs() <= 1 {
+            screen[pixel] = '#';
+        }
+    }
+    screen
+}
+
+/// The screen being drawn one pixel per cycle, below a row showing where the
+/// sprite is.
+fn animate_screen(register_values: Vec<i32>) -> Animation {
+    let mut sprite = None;
+    let frames = (0..240)
+    .map(move |cycle| {
+        let value = register_values[cycle + 1];
+        let pixel = Point::new(cycle as i32 % 40, cycle as i32 / 40 + 1);
+        let mut changes = vec![(pixel, if (value - pixel.x).abs() <= 1 { '#' } else { '.' })];
+        if sprite != Some(value) {
+            changes.extend((0..40).map(|x| (Point::new(x, 0), if (value - x).abs() <= 1 { '=' } else { ' ' })));
+            sprite = Some(value);
+        }
+        Frame { changes, focus: pixel, caption: format!("cycle {}, X = {}", cycle + 1, value) }
+    });
+    Animation {
+        grid: Grid::new(40, 7, ' '),
+        frames: Box::new(frames),
+        palette: &[('#', Color::BrightGreen), ('=', Color::Blue), ('.', Color::Grey)]
+    }
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input.lines()
+    .map(Instruction::parse)
+    .collect()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = Grid<char>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> i32 {
+        let register_values = process(instructions, 1);
+        register_values.iter()
+        .enumerate()
+        .map(|(cycle,value)| cycle as i32 * value)
+        .skip(20)
+        .step_by(40)
+        .sum()
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Grid<char> {
+        draw(process(instructions, 1))
+    }
+}
+
+/// Both parts show the screen being drawn, the first part only asks about
+/// the register while drawing it.
+impl Animate for Day10 {
+    fn animate(instructions: Vec<Instruction>, _part: Part) -> Animation {
+        animate_screen(process(&instructions, 1))
+    }
+}
+
+/// The screen after the program ran, as both parts draw it.
+impl Draw for Day10 {
+    fn draw(instructions: &Vec<Instruction>, _part: Part) -> Image {
+        let screen = draw(process(instructions, 1));
+        Image::from_grid(&screen, 12, |&c| if c == '#' { [120, 255, 120] } else { [10, 20, 10] })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input), 13140);
+        let screen = [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######....."
+        ];
+        assert_eq!(Day10::part2(&input).to_string(), screen.join("\n"));
+        let (grid, _) = Day10::animate(input, Part::Two).finish();
+        let rows: Vec<String> = (1..7).map(|y| grid.row(y).collect()).collect();
+        assert_eq!(rows, screen);
+        let image = Day10::draw(&Day10::parse(EXAMPLE).unwrap(), Part::One);
+        assert_eq!((image.width(), image.height()), (40 * 12, 6 * 12));
+        assert_eq!((image.pixel(0, 0), image.pixel(2 * 12, 0)), ([120, 255, 120], [10, 20, 10]));
+    }
+}
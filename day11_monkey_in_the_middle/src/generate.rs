@@ -0,0 +1,145 @@
+use aoc_core::random::{Rng, SliceRandom, StdRng};
+
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+struct Monkey {
+    items: Vec<u64>,
+    /// `None` squares the worry level, otherwise it is added or multiplied.
+    operand: Option<u64>,
+    multiply: bool,
+    test: u64,
+    targets: [usize; 2]
+}
+
+impl Monkey {
+    fn inspect(&self, old: u64) -> Option<u64> {
+        match (self.operand, self.multiply) {
+            (None, _) => old.checked_mul(old),
+            (Some(value), true) => old.checked_mul(value),
+            (Some(value), false) => old.checked_add(value)
+        }
+    }
+}
+
+/// Whether the 20 rounds of the first part stay within `u64`, as the worry
+/// levels are not reduced there apart from dividing them by three.
+fn fits(monkeys: &[Monkey]) -> bool {
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+    for _ in 0..20 {
+        for (m, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[m]) {
+                let Some(level) = monkey.inspect(item) else { return false };
+                let level = level / 3;
+                items[monkey.targets[(level % monkey.test == 0) as usize]].push(level);
+            }
+        }
+    }
+    true
+}
+
+fn monkey(rng: &mut StdRng, index: usize, count: usize, test: u64, size: usize) -> Monkey {
+    let operand = if rng.gen_bool(0.15) { None } else { Some(rng.gen_range(1..=9)) };
+    let mut targets = [0; 2];
+    for target in &mut targets {
+        *target = (index + rng.gen_range(1..count)) % count;
+    }
+    Monkey {
+        items: (0..rng.gen_range(0..=size)).map(|_| rng.gen_range(50..=99)).collect(),
+        operand,
+        multiply: rng.gen_bool(0.5),
+        test,
+        targets
+    }
+}
+
+/// Between two and eight monkeys with up to `size` items each, testing for
+/// different primes. Inputs that would overflow in the first part are
+/// discarded.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let monkeys = loop {
+        let count = rng.gen_range(2..=8);
+        let mut primes = PRIMES;
+        primes.shuffle(rng);
+        let monkeys: Vec<Monkey> = (0..count).map(|i| monkey(rng, i, count, primes[i], size)).collect();
+        if fits(&monkeys) {
+            break monkeys;
+        }
+    };
+    let descriptions: Vec<String> = monkeys.iter().enumerate()
+    .map(|(i, monkey)| {
+        let items: Vec<String> = monkey.items.iter().map(u64::to_string).collect();
+        let operation = match (monkey.operand, monkey.multiply) {
+            (None, _) => "old * old".to_string(),
+            (Some(value), true) => format!("old * {}", value),
+            (Some(value), false) => format!("old + {}", value)
+        };
+        format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            i, items.join(", "), operation, monkey.test, monkey.targets[1], monkey.targets[0]
+        )
+    })
+    .collect();
+    descriptions.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day11;
+
+    /// The numbers on a line of a monkey's description.
+    fn numbers(line: &str) -> Vec<u64> {
+        line.split(|c: char| !c.is_ascii_digit()).filter_map(|n| n.parse().ok()).collect()
+    }
+
+    /// Plays the rounds and returns the product of the two highest numbers
+    /// of inspections. Without relief, each worry level is kept as its
+    /// remainders for all of the monkeys' tests.
+    fn reference(input: &str, rounds: usize, relief: bool) -> u64 {
+        let monkeys: Vec<Vec<&str>> = input.split("\n\n").map(|m| m.lines().collect()).collect();
+        let tests: Vec<u64> = monkeys.iter().map(|m| numbers(m[3])[0]).collect();
+        let operations: Vec<(&str, Option<u64>)> = monkeys.iter()
+        .map(|m| {
+            let operation: Vec<&str> = m[2].split_whitespace().collect();
+            (operation[4], operation[5].parse().ok())
+        })
+        .collect();
+        let targets: Vec<[usize; 2]> = monkeys.iter()
+        .map(|m| [numbers(m[5])[0] as usize, numbers(m[4])[0] as usize])
+        .collect();
+        let mut items: Vec<VecDeque<Vec<u64>>> = monkeys.iter()
+        .map(|m| numbers(m[1]).iter().map(|&item| vec![item; tests.len()]).collect())
+        .collect();
+        let mut inspections = vec![0; monkeys.len()];
+        for _ in 0..rounds {
+            for m in 0..monkeys.len() {
+                let (operator, operand) = operations[m];
+                while let Some(mut item) = items[m].pop_front() {
+                    inspections[m] += 1;
+                    for (level, test) in item.iter_mut().zip(&tests) {
+                        let operand = operand.unwrap_or(*level);
+                        *level = if operator == "*" { *level * operand } else { *level + operand };
+                        *level = if relief { *level / 3 } else { *level % test };
+                    }
+                    let target = targets[m][(item[m] % tests[m] == 0) as usize];
+                    items[target].push_back(item);
+                }
+            }
+        }
+        inspections.sort();
+        inspections.iter().rev().take(2).product()
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 20, 5, |input| {
+            let monkeys = Day11::parse(input).unwrap();
+            assert_eq!(Day11::part1(&monkeys), reference(input, 20, true));
+            assert_eq!(Day11::part2(&monkeys), reference(input, 10000, false));
+        });
+    }
+}
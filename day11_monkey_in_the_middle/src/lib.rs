@@ -0,0 +1,184 @@
+pub mod generate;
+
+use std::collections::HashMap;
+
+use aoc_core::{parse::{next, number, prefix}, profile::span, ParseError, Solution};
+
+#[derive(Clone)]
+pub enum Operation {
+    Add(Operand, Operand),
+    Sub(Operand, Operand),
+    Mul(Operand, Operand)
+}
+
+#[derive(Clone)]
+pub enum Operand {
+    Constant(u64),
+    Old
+}
+
+impl Operand {
+    fn parse(string: &str) -> Result<Operand, ParseError> {
+        match string {
+            "old" => Ok(Self::Old),
+            a => Ok(Self::Constant(number(a)?))
+        }
+    }
+}
+
+impl Operation {
+    fn get_result(&self, old: u64) -> u64 {
+        let get_value = |op: &Operand| {
+            match op {
+                Operand::Old => old,
+                Operand::Constant(v) => *v
+            }
+        };
+
+        match self {
+            Self::Add(a,b) => get_value(a) + get_value(b),
+            Self::Sub(a,b) => get_value(a) - get_value(b),
+            Self::Mul(a, b) => get_value(a) * get_value(b)
+        }
+    }
+
+    fn parse(expression: &str) -> Result<Operation, ParseError> {
+        let equation: Vec<&str>  = expression.split_whitespace().collect();
+        if equation.len() != 3 {
+            return Err(ParseError::at(expression, "expected an operation like `old * 19`"));
+        }
+        let op1 = Operand::parse(equation[0])?;
+        let op2 = Operand::parse(equation[2])?;
+        match equation[1] {
+            "+" => Ok(Operation::Add(op1, op2)),
+            "-" => Ok(Operation::Sub(op1, op2)),
+            "*" => Ok(Operation::Mul(op1, op2)),
+            op => Err(ParseError::at(op, "invalid operation, expected one of +, -, *"))
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    test: u64,
+    decision: HashMap<bool, usize>,
+    inspections: u64
+}
+
+impl Monkey {
+
+    fn parse(string: &str) -> Result<Monkey, ParseError> {
+        let mut lines = string.lines().map(|l| l.trim());
+        let mut line = |expected: &str| -> Result<&str, ParseError> {
+            let line = next(&mut lines, string, &format!("a line starting with `{}`", expected))?;
+            prefix(line, expected)
+        };
+        line("Monkey ")?;
+        let items_line = line("Starting items:")?;
+        let mut items: Vec<u64> = items_line
+        .split(',')
+        .filter(|item| !item.trim().is_empty())
+        .map(|item| number(item.trim()))
+        .collect::<Result<_, _>>()?;
+        items.reverse();
+        let operation = Operation::parse(line("Operation: new =")?)?;
+        let test: u64 = number(line("Test: divisible by")?.trim())?;
+        if test == 0 {
+            return Err(ParseError::at(string, "cannot test for divisibility by zero"));
+        }
+        let mut decision: HashMap<bool, usize> = HashMap::new();
+        let if_true: usize = number(line("If true: throw to monkey")?.trim())?;
+        let if_false: usize = number(line("If false: throw to monkey")?.trim())?;
+        decision.insert(true, if_true);
+        decision.insert(false, if_false);
+        Ok(Monkey { items, operation, test, decision, inspections: 0})
+    }
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkeys = Vec::new();
+    for monkey_info in input.split("\n\n") {
+        let monkey = span("Monkey::parse", || Monkey::parse(monkey_info))?;
+        monkeys.push( monkey);
+    }
+    for (info, monkey) in input.split("\n\n").zip(&monkeys) {
+        if monkey.decision.values().any(|&target| target >= monkeys.len()) {
+            return Err(ParseError::at(info, format!("monkey throws to a monkey that doesn't exist, there are {}", monkeys.len())));
+        }
+    }
+    Ok(monkeys)
+}
+
+fn run_n_rounds(monkeys: &[Monkey], n: usize, divide_by_three: bool) -> Vec<Monkey> {
+    let mut monkeys = monkeys.to_vec();
+    let common_multiple: u64 = monkeys.iter().map(|m| m.test).product();
+    for _ in 0..n {
+        for m in 0..monkeys.len() {
+            let mut items: Vec<Vec<u64>> = (0..monkeys.len()).map(|_| Vec::new()).collect();
+            let monkey = &mut monkeys[m];
+            while let Some(item) = monkey.items.pop() {
+                let mut level = monkey.operation.get_result(item);
+                if divide_by_three {
+                    level /= 3;
+                } else {
+                    level %= common_multiple;
+                }
+                let test = level.is_multiple_of(monkey.test);
+                let decision = monkey.decision.get(&test).unwrap();
+                items[*decision].insert(0, level);
+                monkey.inspections += 1;
+            }
+            for (i, list) in items.iter().enumerate() {
+                let monkey = &mut monkeys[i];
+                for elem in list {
+                    monkey.items.insert(0, *elem);
+                }
+            }
+        }
+    }
+    monkeys
+}
+
+fn monkey_business(monkeys: &[Monkey], rounds: usize, divide_by_three: bool) -> u64 {
+    let mut monkeys = run_n_rounds(monkeys, rounds, divide_by_three);
+    monkeys.sort_by_key(|m| std::cmp::Reverse(m.inspections));
+    monkeys.iter().take(2).map(|m| m.inspections).product()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part1(monkeys: &Vec<Monkey>) -> u64 {
+        monkey_business(monkeys, 20, true)
+    }
+
+    fn part2(monkeys: &Vec<Monkey>) -> u64 {
+        monkey_business(monkeys, 10000, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input), 10605);
+        assert_eq!(Day11::part2(&input), 2713310158);
+    }
+}
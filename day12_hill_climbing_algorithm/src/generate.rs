@@ -0,0 +1,87 @@
+use std::collections::VecDeque;
+
+use aoc_core::random::{Rng, StdRng};
+
+/// The number of steps from every cell to the cell at `goal`, walking
+/// backwards, i.e. only onto cells at most one lower. Unreachable cells are
+/// `None`.
+fn distances_to(map: &[Vec<u8>], goal: (usize, usize)) -> Vec<Vec<Option<usize>>> {
+    let mut distances = vec![vec![None; map[0].len()]; map.len()];
+    distances[goal.1][goal.0] = Some(0);
+    let mut queue = VecDeque::from([goal]);
+    while let Some((x, y)) = queue.pop_front() {
+        let distance = distances[y][x].unwrap();
+        let neighbours = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+        for (nx, ny) in neighbours {
+            if ny < map.len() && nx < map[0].len() && distances[ny][nx].is_none() && map[ny][nx] + 1 >= map[y][x] {
+                distances[ny][nx] = Some(distance + 1);
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    distances
+}
+
+/// A hill of at least 14 by 14 cells and up to `size` more in each
+/// direction, rising towards the best signal with some pits in its slopes.
+/// `S` is far enough from `E` to climb all the way and can always reach it.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let (width, height) = (rng.gen_range(14..=14 + size), rng.gen_range(14..=14 + size));
+    loop {
+        let goal = (rng.gen_range(0..width), rng.gen_range(0..height));
+        let start = (rng.gen_range(0..width), rng.gen_range(0..height));
+        if start.0.abs_diff(goal.0) + start.1.abs_diff(goal.1) < 25 {
+            continue;
+        }
+        let mut map: Vec<Vec<u8>> = (0..height)
+        .map(|y| (0..width)
+            .map(|x| {
+                let distance = x.abs_diff(goal.0) + y.abs_diff(goal.1);
+                let pit = if rng.gen_bool(0.1) { 3 } else { 0 };
+                25u8.saturating_sub(distance as u8).saturating_sub(pit)
+            })
+            .collect()
+        )
+        .collect();
+        map[goal.1][goal.0] = 25;
+        map[start.1][start.0] = 0;
+        if distances_to(&map, goal)[start.1][start.0].is_none() {
+            continue;
+        }
+        let mut rows: Vec<String> = map.iter().map(|row| row.iter().map(|&h| (b'a' + h) as char).collect()).collect();
+        rows[goal.1].replace_range(goal.0..goal.0 + 1, "E");
+        rows[start.1].replace_range(start.0..start.0 + 1, "S");
+        return rows.join("\n") + "\n";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day12;
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 20, |input| {
+            let graph = Day12::parse(input).unwrap();
+            let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+            let find = |marker| rows.iter().enumerate()
+                .find_map(|(y, row)| row.iter().position(|&c| c == marker).map(|x| (x, y)))
+                .unwrap();
+            let (start, goal) = (find(b'S'), find(b'E'));
+            let map: Vec<Vec<u8>> = rows.iter()
+            .map(|row| row.iter().map(|&c| match c { b'S' => 0, b'E' => 25, c => c - b'a' }).collect())
+            .collect();
+            let distances = distances_to(&map, goal);
+            let fewest = (0..map.len())
+            .flat_map(|y| (0..map[0].len()).map(move |x| (x, y)))
+            .filter(|&(x, y)| map[y][x] == 0)
+            .filter_map(|(x, y)| distances[y][x])
+            .min();
+            assert_eq!(Day12::part1(&graph), distances[start.1][start.0].unwrap());
+            assert_eq!(Day12::part2(&graph), fewest.unwrap());
+        });
+    }
+}
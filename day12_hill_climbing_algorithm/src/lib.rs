@@ -0,0 +1,153 @@
+pub mod generate;
+
+use aoc_core::{profile::span, ParseError, Part, Solution};
+use aoc_grid::{image::{Draw, Image, Rgb}, BoundingBox, Grid, Point};
+use petgraph::{algo, graph::NodeIndex, prelude::DiGraph, Graph};
+
+fn elevation(marker: char) -> i32 {
+    match marker {
+        'S' => 'a' as i32,
+        'E' => 'z' as i32,
+        e => e as i32
+    }
+}
+
+fn read_map(input: &str) -> Result<DiGraph<(Point, char), i32>, ParseError> {
+    let map = Grid::parse(input, |c| match c {
+        'a'..='z' | 'S' | 'E' => Ok(c),
+        _ => Err("invalid elevation, expected `a`-`z`, `S` or `E`".to_string())
+    })?;
+    for marker in ['S', 'E'] {
+        if map.iter().filter(|&(_, &c)| c == marker).count() != 1 {
+            return Err(ParseError::at(input, format!("expected exactly one `{}` on the map", marker)));
+        }
+    }
+
+    let mut graph = DiGraph::new();
+    let mut nodes = Grid::new(map.width(), map.height(), NodeIndex::end());
+    for (position, &c) in map.iter() {
+        nodes[position] = graph.add_node((position, c));
+    }
+    for (position, &c) in map.iter() {
+        for neighbour in map.neighbours_4(position) {
+            if elevation(c) - elevation(map[neighbour]) >= -1 {
+                graph.add_edge(nodes[position], nodes[neighbour], 1);
+            }
+        }
+    }
+    Ok(graph)
+}
+
+fn find_shortest_path(graph: &Graph<(Point, char), i32>, start: Point) -> Option<Vec<Point>> {
+    let end = graph.node_indices().find(|n| graph[*n].1 == 'E').unwrap();
+    let goal = graph[end].0;
+    let start = graph
+        .node_indices()
+        .find(|n| graph[*n].0 == start)
+        .unwrap();
+    let result = algo::astar(
+        graph,
+        start,
+        |n| n == end,
+        |_| 1,
+        |n| graph[n].0.manhattan(goal),
+    );
+
+    result.map(|(_, path)| path.iter().map(|&n| graph[n].0).collect())
+}
+
+fn start_of_path(graph: &Graph<(Point, char), i32>) -> Point {
+    graph.node_weights().find(|(_, e)| *e == 'S').unwrap().0
+}
+
+/// The shortest path to `E` from any square of the lowest elevation.
+fn shortest_hike(graph: &Graph<(Point, char), i32>) -> Vec<Point> {
+    graph.node_weights()
+    .filter(|(_, e)| *e == 'a' || *e == 'S')
+    .filter_map(|&(start, _)| span("find_shortest_path", || find_shortest_path(graph, start)))
+    .min_by_key(|path| path.len())
+    .unwrap()
+}
+
+/// From green valleys to white peaks.
+fn terrain(marker: char) -> Rgb {
+    let t = (elevation(marker) - 'a' as i32) as f64 / 25.0;
+    let mix = |a: f64, b: f64, f: f64| (a + (b - a) * f).round() as u8;
+    if t < 0.5 {
+        let f = t * 2.0;
+        [mix(40.0, 140.0, f), mix(100.0, 110.0, f), mix(40.0, 60.0, f)]
+    } else {
+        let f = (t - 0.5) * 2.0;
+        [mix(140.0, 245.0, f), mix(110.0, 245.0, f), mix(60.0, 245.0, f)]
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = DiGraph<(Point, char), i32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_map(input)
+    }
+
+    fn part1(graph: &Self::Input) -> usize {
+        let shortest_path = find_shortest_path(graph, start_of_path(graph)).unwrap();
+        shortest_path.len() - 1
+    }
+
+    fn part2(graph: &Self::Input) -> usize {
+        shortest_hike(graph).len() - 1
+    }
+}
+
+/// The height map with the shortest path of the part drawn in red.
+impl Draw for Day12 {
+    fn draw(graph: &Self::Input, part: Part) -> Image {
+        let bounds = BoundingBox::of(graph.node_weights().map(|&(position, _)| position)).unwrap();
+        let mut map = Grid::with_bounds(bounds, 'a');
+        for &(position, marker) in graph.node_weights() {
+            map[position] = marker;
+        }
+        let path = match part {
+            Part::One => find_shortest_path(graph, start_of_path(graph)).unwrap(),
+            Part::Two => shortest_hike(graph)
+        };
+        let mut image = Image::from_grid(&map, 8, |&marker| terrain(marker));
+        image.overlay(path.iter().map(|&position| (position, [220, 30, 30])));
+        image.overlay([(path[0], [255, 220, 0]), (path[path.len() - 1], [30, 60, 255])]);
+        image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input), 31);
+        assert_eq!(Day12::part2(&input), 29);
+    }
+
+    #[test]
+    fn draws_path_over_height_map() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        let image = Day12::draw(&input, Part::One);
+        assert_eq!((image.width(), image.height()), (8 * 8, 5 * 8));
+        assert_eq!(image.pixel(0, 0), [255, 220, 0]);
+        assert_eq!(image.pixel(5 * 8, 2 * 8), [30, 60, 255]);
+        let red = (0..image.height())
+        .flat_map(|y| (0..image.width()).map(move |x| (x, y)))
+        .filter(|&(x, y)| image.pixel(x, y) == [220, 30, 30])
+        .count();
+        assert_eq!(red, 30 * 8 * 8);
+    }
+}
@@ -0,0 +1,136 @@
+use std::{cmp::Ordering, fmt};
+
+use aoc_core::random::{Rng, StdRng};
+
+/// A packet as a plain tree, ordered by the rules of the puzzle.
+#[derive(Clone, Debug)]
+enum Packet {
+    Number(u32),
+    List(Vec<Packet>)
+}
+
+impl Packet {
+    fn divider(value: u32) -> Packet {
+        Packet::List(vec![Packet::List(vec![Packet::Number(value)])])
+    }
+
+    /// A random packet of at most `depth` levels. Every now and then a long
+    /// chain of single element lists is wrapped around it.
+    fn random(rng: &mut StdRng, depth: usize) -> Packet {
+        if depth == 0 || rng.gen_bool(0.3) {
+            return Packet::Number(rng.gen_range(0..=10));
+        }
+        let mut packet = Packet::List((0..rng.gen_range(0..=4)).map(|_| Packet::random(rng, depth - 1)).collect());
+        if rng.gen_bool(0.05) {
+            for _ in 0..rng.gen_range(1..=depth * 5) {
+                packet = Packet::List(vec![packet]);
+            }
+        }
+        packet
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Packet) -> Ordering {
+        match (self, other) {
+            (Packet::Number(a), Packet::Number(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => a.cmp(b),
+            (Packet::Number(_), Packet::List(b)) => std::slice::from_ref(self).cmp(b),
+            (Packet::List(a), Packet::Number(_)) => a[..].cmp(std::slice::from_ref(other))
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Packet) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Packet) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Packet::Number(n) => write!(f, "{}", n),
+            Packet::List(list) => {
+                let items: Vec<String> = list.iter().map(Packet::to_string).collect();
+                write!(f, "[{}]", items.join(","))
+            }
+        }
+    }
+}
+
+/// `size` pairs of packets, nested up to `size` levels deep and sometimes
+/// far deeper. No pair is equal and no packet equals a divider packet, as
+/// neither is ordered.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let dividers = [Packet::divider(2), Packet::divider(6)];
+    let mut packet = || loop {
+        let packet = Packet::List((0..rng.gen_range(0..=4)).map(|_| Packet::random(rng, size)).collect());
+        if !dividers.contains(&packet) {
+            return packet;
+        }
+    };
+    let mut pairs = Vec::new();
+    while pairs.len() < size {
+        let (left, right) = (packet(), packet());
+        if left != right {
+            pairs.push(format!("{}\n{}\n", left, right));
+        }
+    }
+    pairs.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day13;
+
+    /// Parses the packet at the start of `text`, returning the rest.
+    fn parse(text: &str) -> (Packet, &str) {
+        if let Some(mut rest) = text.strip_prefix('[') {
+            let mut list = Vec::new();
+            while let Some(after) = rest.strip_prefix(',').or((!rest.starts_with(']')).then_some(rest)) {
+                let (packet, after) = parse(after);
+                list.push(packet);
+                rest = after;
+            }
+            (Packet::List(list), &rest[1..])
+        } else {
+            let end = text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+            (Packet::Number(text[..end].parse().unwrap()), &text[end..])
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 10, |input| {
+            let signals = Day13::parse(input).unwrap();
+            let packets: Vec<Packet> = input.lines().filter(|l| !l.is_empty()).map(|l| parse(l).0).collect();
+            let ordered: usize = packets.chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0] < pair[1])
+            .map(|(i, _)| i + 1)
+            .sum();
+            let before = |divider: Packet| packets.iter().filter(|&p| *p < divider).count();
+            let key = (before(Packet::divider(2)) + 1) * (before(Packet::divider(6)) + 2);
+            assert_eq!(Day13::part1(&signals), ordered);
+            assert_eq!(Day13::part2(&signals), key);
+        });
+    }
+
+    #[test]
+    fn parses_what_it_prints() {
+        let packet = parse("[[1],[2,[]],3,[[[10]]]]").0;
+        assert_eq!(packet.to_string(), "[[1],[2,[]],3,[[[10]]]]");
+    }
+}
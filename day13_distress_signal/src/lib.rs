@@ -0,0 +1,186 @@
+pub mod generate;
+
+use std::cmp::Ordering;
+
+use aoc_core::{parse::{number, split_once}, ParseError, Solution};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Signal {
+    List(Vec<Signal>),
+    Number(i32),
+}
+
+impl Signal {
+    fn parse(line: &str) -> Result<Signal, ParseError> {
+        let mut stack: Vec<Signal> = Vec::new();
+        let mut current_list = Vec::new();
+        let mut number_start: Option<usize> = None;
+        for (i, c) in line.char_indices() {
+            if c == '[' {
+                stack.push(Signal::List(current_list.clone()));
+                current_list.clear();
+            } else if c == ']' {
+                if let Some(start) = number_start.take() {
+                    let value: i32 = number(&line[start..i])?;
+                    current_list.push(Signal::Number(value));
+                }
+                let Some(Signal::List(list)) = stack.pop() else {
+                    return Err(ParseError::at(&line[i..i + 1], "unmatched closing bracket"));
+                };
+                let mut new_list = list.clone();
+                let complete_list = Signal::List(current_list.clone());
+                new_list.push(complete_list);
+                current_list = new_list.clone();
+            } else if c == ',' {
+                if let Some(start) = number_start.take() {
+                    let value: i32 = number(&line[start..i])?;
+                    current_list.push(Signal::Number(value));
+                }
+            } else if c.is_ascii_digit() {
+                number_start.get_or_insert(i);
+            } else {
+                return Err(ParseError::at(&line[i..i + c.len_utf8()], "unexpected character, expected a digit, `[`, `]` or `,`"));
+            }
+        }
+        if !stack.is_empty() {
+            return Err(ParseError::after(line, "unclosed bracket"));
+        }
+        match current_list.as_slice() {
+            [signal @ Signal::List(_)] => Ok(signal.clone()),
+            _ => Err(ParseError::at(line, "expected a single list"))
+        }
+    }
+
+    fn divider(value: i32) -> Signal {
+        Signal::List(vec![Signal::List(vec![Signal::Number(value)])])
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Signal, Signal)>, ParseError> {
+    let mut signals = Vec::new();
+    for pair in input.split("\n\n") {
+        let (a,b) = split_once(pair.trim_end(), "\n")?;
+        signals.push((Signal::parse(a.trim_end())?, Signal::parse(b.trim_end())?));
+    }
+    Ok(signals)
+}
+
+fn check_order(a: &Signal, b: &Signal) -> Option<bool> {
+    match (a,b) {
+        (Signal::List(l1), Signal::List(l2)) => {
+            let mut result = Option::None;
+            let mut i = 0;
+            while result.is_none() {
+                if i < l1.len() && i < l2.len(){
+                    result = check_order(&l1[i], &l2[i]);
+                } else if i == l1.len() && i < l2.len() {
+                    return Some(true);
+                } else if i < l1.len() && i == l2.len() {
+                    return Some(false);
+                } else {
+                    return None;
+                }
+                i += 1
+            }
+            result
+        },
+        (Signal::Number(n1), Signal::Number(n2)) => {
+            if n1 != n2 {
+                Option::Some(n1 < n2)
+            } else {
+                Option::None
+            }
+        },
+        (Signal::Number(_), Signal::List(_)) => {
+            let list = [a.clone()].to_vec();
+            check_order(&Signal::List(list), b)
+        },
+        (Signal::List(_), Signal::Number(_)) => {
+            let list = [b.clone()].to_vec();
+            check_order(a, &Signal::List(list))
+        }
+    }
+}
+
+impl PartialOrd for Signal {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let result = check_order(self, other);
+        if let Some(v) = result {
+            if v {
+                Some(Ordering::Less) 
+            } else {
+                Some(Ordering::Greater)
+            }
+        } else {
+            Some(Ordering::Equal)
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<(Signal, Signal)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Signal, Signal)>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<(Signal, Signal)>) -> usize {
+        input.iter()
+        .enumerate()
+        .filter(|&(_, pair)| check_order(&pair.0, &pair.1).unwrap())
+        .map(|(i, _)| i + 1)
+        .sum()
+    }
+
+    fn part2(input: &Vec<(Signal, Signal)>) -> usize {
+        let mut signals: Vec<Signal> = input.iter()
+        .flat_map(|t| [t.0.clone(), t.1.clone()].to_vec())
+        .collect();
+
+        signals.push(Signal::divider(2));
+        signals.push(Signal::divider(6));
+        signals.sort_by(|a,b| a.partial_cmp(b).unwrap());
+
+        let idx_1: usize = signals.iter()
+        .enumerate()
+        .find(|&(_, s)| *s == Signal::divider(2))
+        .unwrap().0 + 1;
+
+        let idx_2 = signals.iter()
+        .enumerate()
+        .find(|&(_, s)| *s == Signal::divider(6))
+        .unwrap().0 + 1;
+
+        idx_1 * idx_2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&input), 13);
+        assert_eq!(Day13::part2(&input), 140);
+    }
+
+    #[test]
+    fn pairs_in_order() {
+        let pairs = Day13::parse(EXAMPLE).unwrap();
+        let ordered: Vec<Option<bool>> = pairs.iter()
+        .map(|(a, b)| check_order(a, b))
+        .collect();
+        let expected = [true, true, false, true, false, true, false, false];
+        assert_eq!(ordered, expected.map(Some));
+    }
+}
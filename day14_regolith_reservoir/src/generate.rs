@@ -0,0 +1,81 @@
+use aoc_core::random::{Rng, StdRng};
+
+/// `size` rock paths below the source of the sand, each made of up to four
+/// horizontal or vertical lines.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let spread = 3 * size as i32 + 5;
+    (0..size)
+    .map(|_| {
+        let mut point = (rng.gen_range(500 - spread..=500 + spread), rng.gen_range(1..=spread));
+        let mut path = vec![format!("{},{}", point.0, point.1)];
+        for i in 0..rng.gen_range(1..=4) {
+            let length = rng.gen_range(-6..=6);
+            if i % 2 == 0 {
+                point.0 += length;
+            } else {
+                point.1 = (point.1 + length).max(1);
+            }
+            path.push(format!("{},{}", point.0, point.1));
+        }
+        path.join(" -> ") + "\n"
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day14;
+
+    /// Drops sand grain by grain until one falls below all rock, or until
+    /// the source is covered when there is a floor.
+    fn reference(input: &str, with_floor: bool) -> usize {
+        let mut blocked = HashSet::new();
+        for line in input.lines() {
+            let points: Vec<(i32, i32)> = line.split(" -> ")
+            .map(|p| p.split_once(',').unwrap())
+            .map(|(x, y)| (x.parse().unwrap(), y.parse().unwrap()))
+            .collect();
+            for pair in points.windows(2) {
+                let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+                for x in x1.min(x2)..=x1.max(x2) {
+                    for y in y1.min(y2)..=y1.max(y2) {
+                        blocked.insert((x, y));
+                    }
+                }
+            }
+        }
+        let bottom = blocked.iter().map(|&(_, y)| y).max().unwrap();
+        let rocks = blocked.len();
+        while !blocked.contains(&(500, 0)) {
+            let (mut x, mut y) = (500, 0);
+            loop {
+                if y == bottom + 1 {
+                    break;
+                }
+                match [x, x - 1, x + 1].into_iter().find(|&next| !blocked.contains(&(next, y + 1))) {
+                    Some(next) => (x, y) = (next, y + 1),
+                    None => break
+                }
+            }
+            if y == bottom + 1 && !with_floor {
+                break;
+            }
+            blocked.insert((x, y));
+        }
+        blocked.len() - rocks
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 10, |input| {
+            let lines = Day14::parse(input).unwrap();
+            assert_eq!(Day14::part1(&lines), reference(input, false));
+            assert_eq!(Day14::part2(&lines), reference(input, true));
+        });
+    }
+}
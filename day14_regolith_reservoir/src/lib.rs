@@ -0,0 +1,253 @@
+pub mod generate;
+
+use aoc_core::{parse::{number, split_once}, ParseError, Part, Solution};
+use aoc_grid::{
+    animation::{Animate, Animation, Color, Frame},
+    image::{Draw, Image},
+    BoundingBox,
+    Grid,
+    Point
+};
+
+type Line = Vec<Point>;
+
+/// The cave, with `#` for rock and `o` for resting sand. Without a floor,
+/// sand leaving the grid falls into the abyss.
+struct Map {
+    grid: Grid<char>,
+    floor: Option<i32>
+}
+impl Map {
+    fn from_lines(lines: &[Line], start: &Point, with_floor: bool) -> Map {
+        let mut bounds = BoundingBox::of(lines.iter().flatten().chain([start]).copied()).unwrap();
+        let floor = bounds.max.y + 2;
+        if with_floor {
+            // Sand piles up at most as far to the sides as the floor is deep
+            bounds.extend(Point::new(start.x - floor, floor - 1));
+            bounds.extend(Point::new(start.x + floor, floor - 1));
+        }
+        let mut grid = Grid::with_bounds(bounds, '.');
+        for line in lines {
+            for pair in line.windows(2) {
+                let (mut rock, end) = (pair[0], pair[1]);
+                let step = (end - rock).signum();
+                grid[rock] = '#';
+                while rock != end {
+                    rock += step;
+                    grid[rock] = '#';
+                }
+            }
+        }
+        Map { grid, floor: with_floor.then_some(floor) }
+    }
+
+    fn is_free(&self, point: &Point) -> bool {
+        self.floor != Some(point.y) && self.grid.get(*point).is_none_or(|&c| c == '.')
+    }
+
+    /// The next position of sand at `current_pos`, which is the same if it
+    /// comes to rest, or `None` if it falls into the abyss.
+    fn move_sand(&self, current_pos: &Point) -> Option<Point> {
+        for dx in [0, -1, 1] {
+            let next = *current_pos + Point::new(dx, 1);
+            if self.floor.is_none() && !self.grid.contains(next) {
+                return Option::None;
+            }
+            if self.is_free(&next) {
+                return Option::Some(next);
+            }
+        }
+        Option::Some(*current_pos)
+    }
+
+    fn fill_with_sand(&mut self, start: &Point) {
+        while self.grid[*start] == '.' {
+            let mut pos = *start;
+            loop {
+                match self.move_sand(&pos) {
+                    Some(next) if next == pos => break,
+                    Some(next) => pos = next,
+                    None => return
+                }
+            }
+            self.grid[pos] = 'o';
+        }
+    }
+}
+fn coordinate(token: &str) -> Result<i32, ParseError> {
+    let value: i32 = number(token)?;
+    if value < 0 {
+        return Err(ParseError::at(token, "coordinates must not be negative"));
+    }
+    Ok(value)
+}
+
+fn parse_point(point: &str) -> Result<Point, ParseError> {
+    let (x, y) = split_once(point, ",")?;
+    Ok(Point::new(coordinate(x)?, coordinate(y)?))
+}
+
+fn parse_lines(input: &str) -> Result<Vec<Line>, ParseError> {
+    let mut lines = Vec::new();
+    for l in input.lines() {
+        let points: Vec<&str> = l.split(" -> ").collect();
+        let line: Line = points.iter()
+            .map(|p| parse_point(p))
+            .collect::<Result<_, _>>()?;
+        for (i, pair) in line.windows(2).enumerate() {
+            if pair[0].x != pair[1].x && pair[0].y != pair[1].y {
+                return Err(ParseError::at(points[i + 1], "rock paths must be horizontal or vertical"));
+            }
+        }
+        lines.push(line)
+    }
+    if lines.is_empty() {
+        return Err(ParseError::at(input, "expected at least one rock path"));
+    }
+    Ok(lines)
+}
+fn count_sand(map: &Map) -> usize {
+    map.grid.iter()
+    .filter(|&(_, &c)| c == 'o')
+    .count()
+}
+
+fn fill(lines: &[Line], with_floor: bool) -> Map {
+    let start = Point::new(500, 0);
+    let mut map = Map::from_lines(lines, &start, with_floor);
+    map.fill_with_sand(&start);
+    map
+}
+
+fn count_sand_after_filling(lines: &[Line], with_floor: bool) -> usize {
+    count_sand(&fill(lines, with_floor))
+}
+
+/// Sand falling one step per frame, until the source is blocked or sand
+/// falls into the abyss.
+struct Pour {
+    map: Map,
+    start: Point,
+    grain: Option<Point>,
+    resting: usize
+}
+
+impl Pour {
+    /// How `point` is shown without falling sand, with `+` for a free source.
+    fn cell(&self, point: Point) -> char {
+        match self.map.grid[point] {
+            '.' if point == self.start => '+',
+            c => c
+        }
+    }
+}
+
+impl Iterator for Pour {
+    type Item = Frame;
+
+    fn next(&mut self) -> Option<Frame> {
+        let grain = self.grain?;
+        match self.map.move_sand(&grain) {
+            Some(next) if next == grain => {
+                self.map.grid[grain] = 'o';
+                self.resting += 1;
+                self.grain = (self.map.grid[self.start] == '.').then_some(self.start);
+            },
+            next => self.grain = next
+        }
+        let mut changes = vec![(grain, self.cell(grain))];
+        changes.extend(self.grain.map(|grain| (grain, '~')));
+        let caption = format!("{} units of sand at rest", self.resting);
+        Some(Frame { changes, focus: self.grain.unwrap_or(grain), caption })
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(lines: &Vec<Line>) -> usize {
+        count_sand_after_filling(lines, false)
+    }
+
+    fn part2(lines: &Vec<Line>) -> usize {
+        count_sand_after_filling(lines, true)
+    }
+}
+
+impl Animate for Day14 {
+    fn animate(lines: Vec<Line>, part: Part) -> Animation {
+        let start = Point::new(500, 0);
+        let map = Map::from_lines(&lines, &start, part == Part::Two);
+        let pour = Pour { map, start, grain: Some(start), resting: 0 };
+        let mut grid = pour.map.grid.clone();
+        grid[start] = pour.cell(start);
+        Animation {
+            grid,
+            frames: Box::new(pour),
+            palette: &[('#', Color::White), ('o', Color::Yellow), ('~', Color::BrightYellow), ('+', Color::BrightRed), ('.', Color::Grey)]
+        }
+    }
+}
+
+/// A cross section of the cave once the sand has settled, including the
+/// floor of the second part.
+impl Draw for Day14 {
+    fn draw(lines: &Vec<Line>, part: Part) -> Image {
+        let Map { mut grid, floor } = fill(lines, part == Part::Two);
+        if let Some(floor) = floor {
+            let BoundingBox { min, max } = grid.bounds();
+            grid.grow_to(Point::new(min.x, floor), '#');
+            for x in min.x..=max.x {
+                grid[Point::new(x, floor)] = '#';
+            }
+        }
+        Image::from_grid(&grid, 4, |&c| match c {
+            '#' => [110, 100, 90],
+            'o' => [230, 190, 90],
+            _ => [20, 24, 40]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part1(&input), 24);
+        assert_eq!(Day14::part2(&input), 93);
+    }
+
+    #[test]
+    fn animates_falling_sand() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        let (grid, last) = Day14::animate(input, Part::One).finish();
+        assert_eq!(last.unwrap().caption, "24 units of sand at rest");
+        assert_eq!(grid.iter().filter(|&(_, &c)| c == 'o').count(), 24);
+        assert_eq!(grid[Point::new(500, 0)], '+');
+    }
+
+    #[test]
+    fn draws_cross_section_with_floor() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::draw(&input, Part::One).height(), 10 * 4);
+        let image = Day14::draw(&input, Part::Two);
+        assert_eq!(image.height(), 12 * 4);
+        assert_eq!(image.pixel(0, image.height() - 1), [110, 100, 90]);
+        assert_eq!(image.pixel(image.width() / 2, 0), [230, 190, 90]);
+    }
+}
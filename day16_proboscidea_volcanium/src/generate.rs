@@ -0,0 +1,128 @@
+use aoc_core::random::{Rng, SliceRandom, StdRng};
+
+/// A connected cave of `size + 1` valves, starting at `AA`. At most seven
+/// valves release pressure, the others only join tunnels.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let count = size + 1;
+    let mut names: Vec<String> = Vec::new();
+    names.push("AA".to_string());
+    while names.len() < count {
+        let name: String = (0..2).map(|_| rng.gen_range('A'..='Z')).collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    let mut tunnels: Vec<Vec<usize>> = vec![Vec::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for valve in 1..count {
+        connect(valve, rng.gen_range(0..valve));
+    }
+    for _ in 0..count / 3 {
+        connect(rng.gen_range(0..count), rng.gen_range(0..count));
+    }
+    let mut rates = vec![0; count];
+    let mut working: Vec<usize> = (1..count).collect();
+    working.shuffle(rng);
+    for &valve in working.iter().take(7) {
+        if rng.gen_bool(0.8) {
+            rates[valve] = rng.gen_range(1..=25);
+        }
+    }
+    let mut lines: Vec<String> = (0..count)
+    .map(|valve| {
+        let neighbours: Vec<&str> = tunnels[valve].iter().map(|&n| names[n].as_str()).collect();
+        let tunnels = if neighbours.len() == 1 { "tunnel leads to valve" } else { "tunnels lead to valves" };
+        format!("Valve {} has flow rate={}; {} {}\n", names[valve], rates[valve], tunnels, neighbours.join(", "))
+    })
+    .collect();
+    lines.shuffle(rng);
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, VecDeque};
+
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day16;
+
+    struct Cave {
+        /// Minutes to walk between the start and the working valves, which
+        /// come first.
+        distances: Vec<Vec<usize>>,
+        rates: Vec<usize>
+    }
+
+    fn cave(input: &str) -> Cave {
+        let lines: Vec<Vec<&str>> = input.lines()
+        .map(|line| line.split([' ', '=', ';', ',']).filter(|t| !t.is_empty()).collect())
+        .collect();
+        let index: HashMap<&str, usize> = lines.iter().enumerate().map(|(i, tokens)| (tokens[1], i)).collect();
+        let walk = |start: usize| {
+            let mut distances = vec![usize::MAX; lines.len()];
+            distances[start] = 0;
+            let mut queue = VecDeque::from([start]);
+            while let Some(valve) = queue.pop_front() {
+                for name in &lines[valve][10..] {
+                    let next = index[name];
+                    if distances[next] == usize::MAX {
+                        distances[next] = distances[valve] + 1;
+                        queue.push_back(next);
+                    }
+                }
+            }
+            distances
+        };
+        let rate = |i: usize| lines[i][5].parse::<usize>().unwrap();
+        let mut valves: Vec<usize> = (0..lines.len()).filter(|&i| rate(i) > 0).collect();
+        valves.push(index["AA"]);
+        let all: Vec<Vec<usize>> = (0..lines.len()).map(walk).collect();
+        Cave {
+            distances: valves.iter().map(|&a| valves.iter().map(|&b| all[a][b]).collect()).collect(),
+            rates: valves.iter().map(|&v| rate(v)).collect()
+        }
+    }
+
+    /// Tries every order of opening valves, recording the most pressure
+    /// released for each set of opened valves.
+    fn explore(cave: &Cave, at: usize, time: usize, opened: u32, released: usize, best: &mut HashMap<u32, usize>) {
+        let entry = best.entry(opened).or_insert(0);
+        *entry = (*entry).max(released);
+        for valve in 0..cave.rates.len() - 1 {
+            let cost = cave.distances[at][valve] + 1;
+            if opened & 1 << valve == 0 && cost < time {
+                let time = time - cost;
+                explore(cave, valve, time, opened | 1 << valve, released + time * cave.rates[valve], best);
+            }
+        }
+    }
+
+    fn best_per_set(cave: &Cave, time: usize) -> HashMap<u32, usize> {
+        let mut best = HashMap::new();
+        explore(cave, cave.rates.len() - 1, time, 0, 0, &mut best);
+        best
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 40, 12, |input| {
+            let system = Day16::parse(input).unwrap();
+            let cave = cave(input);
+            let alone = best_per_set(&cave, 30).into_values().max().unwrap();
+            let sets = best_per_set(&cave, 26);
+            let together = sets.iter()
+            .flat_map(|(&mine, &a)| sets.iter().filter(move |&(&theirs, _)| mine & theirs == 0).map(move |(_, &b)| a + b))
+            .max()
+            .unwrap();
+            assert_eq!(Day16::part1(&system), alone);
+            assert_eq!(Day16::part2(&system), together);
+        });
+    }
+}
@@ -0,0 +1,340 @@
+pub mod generate;
+
+use aoc_core::{parse::{next, number, prefix}, profile::span, ParseError, Solution};
+use petgraph::Graph;
+use priority_queue::PriorityQueue;
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State {
+    time_left: usize,
+    /// Minutes until me and the elephant arrive at our target valve.
+    moving_duration: (usize, usize),
+    next_player: Player,
+    /// Indices of the valves me and the elephant are at or heading to.
+    position: (usize, usize),
+    valve_states: Vec<bool>,
+    actions: Vec<Action>,
+    flow: usize
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Action {
+    Open(usize),
+    /// Stop for the rest of the time and leave the remaining valves to the
+    /// other player.
+    Rest
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Player {
+    Me,
+    Elephant
+}
+
+#[derive(Clone)]
+pub struct ValveSystem {
+    valves: Vec<String>,
+    distance_matrix: Vec<Vec<usize>>,
+    flow_rates: Vec<usize>,
+    is_two_player: bool
+}
+
+impl ValveSystem {
+
+    fn get_index(valves: &[String], name: &str) -> Option<usize> {
+        valves.iter().position(|v| v == name)
+    }
+
+    fn parse(input: &str, is_two_player: bool) -> Result<ValveSystem, ParseError> {
+        let mut names: Vec<&str> = Vec::new();
+        for line in input.lines() {
+            let mut items = line.split_whitespace();
+            let keyword = next(&mut items, line, "`Valve`")?;
+            if keyword != "Valve" {
+                return Err(ParseError::at(keyword, "expected `Valve`"));
+            }
+            names.push(next(&mut items, line, "a valve name")?);
+        }
+        let mut valves: Vec<String> = names.iter().map(|n| n.to_string()).collect();
+        valves.sort();
+        if let Some(i) = (1..valves.len()).find(|&i| valves[i - 1] == valves[i]) {
+            let duplicate = names.iter().rev().find(|n| **n == valves[i]).unwrap();
+            return Err(ParseError::at(duplicate, "valve is defined twice"));
+        }
+        if Self::get_index(&valves, "AA").is_none() {
+            return Err(ParseError::at(input, "expected a starting valve `AA`"));
+        }
+
+        let mut adjacency_list: Vec<Vec<usize>> = vec![Vec::new(); valves.len()];
+        let mut flow_rates = vec![0; valves.len()];
+
+        for line in input.lines() {
+            let items: Vec<&str> = line.split_whitespace().collect();
+            if items.len() < 10 {
+                return Err(ParseError::at(line, "expected `Valve <name> has flow rate=<rate>; tunnels lead to valves <names>`"));
+            }
+
+            let node_index = Self::get_index(&valves, items[1]).unwrap();
+            let rate_token = prefix(items[4], "rate=")?;
+            let rate: usize = number(rate_token.strip_suffix(';').unwrap_or(rate_token))?;
+            let neighbours: Vec<usize> = items[9..items.len()].iter()
+            .map(|n| n.strip_suffix(',').unwrap_or(n))
+            .map(|n| Self::get_index(&valves, n).ok_or_else(|| ParseError::at(n, "tunnel leads to an unknown valve")))
+            .collect::<Result<_, _>>()?;
+
+            adjacency_list[node_index] = neighbours;
+            flow_rates[node_index] = rate;
+        }
+
+        let distance_matrix = span("compute_distance_matrix", || Self::compute_distance_matrix(&adjacency_list));
+
+        Ok(ValveSystem {
+            valves,
+            flow_rates,
+            distance_matrix,
+            is_two_player
+        })
+    }
+
+    fn compute_distance_matrix(adjacency_list: &[Vec<usize>]) -> Vec<Vec<usize>> {
+        let mut edges = Vec::new();
+        for (u, neighbours) in adjacency_list.iter().enumerate() {
+            for &v in neighbours {
+                edges.push((u as u32,v as u32));
+            }
+        }
+
+        let graph = Graph::<(), i32>::from_edges(edges);
+        let mut distances: Vec<Vec<usize>> = Vec::new();
+        for start in graph.node_indices() {
+            let min_dists = petgraph::algo::dijkstra(
+                &graph,
+                start, 
+                None,
+                |_| 1
+            );
+            let mut distances_from_node: Vec<usize> = (0..graph.node_count()).map(|_| usize::MAX).collect();
+            for (node, distance) in min_dists.iter() {
+                if start != *node {
+                    distances_from_node[node.index()] = *distance as usize + 1;
+                }
+            }
+            distances.push(distances_from_node);
+        }
+        distances
+    }
+
+    fn expand(&self, state: &State, action: Action) -> State {
+        let mut valve_states = state.valve_states.clone();
+        let mut actions = state.actions.clone();
+        actions.push(action);
+        let (time_wait, elephant_time_wait) = state.moving_duration;
+        let target = match action {
+            Action::Open(target) => target,
+            Action::Rest => {
+                let (moving_duration, next_player, waited) = match state.next_player {
+                    Player::Me => ((usize::MAX, 0), Player::Elephant, elephant_time_wait),
+                    Player::Elephant => ((0, usize::MAX), Player::Me, time_wait)
+                };
+                return State { moving_duration, next_player, time_left: state.time_left - waited, actions, ..state.clone() };
+            }
+        };
+        valve_states[target] = true;
+
+        let (cur_pos, elephant_pos) = state.position;
+
+        let moving_duration;
+        let position;
+        let mut flow = state.flow;
+        let mut time_left = state.time_left;
+
+
+        let next_player = match state.next_player {
+            Player::Me => {
+                let duration = self.distance_matrix[cur_pos][target];
+                position = (target, elephant_pos);
+                flow += (time_left-duration) * self.flow_rates[target];
+                if duration < elephant_time_wait {
+                    moving_duration = (0, elephant_time_wait-duration);
+                    time_left -=  duration;    
+                } else {
+                    moving_duration = (duration-elephant_time_wait, 0);
+                    time_left -= elephant_time_wait;
+                   
+                }
+                match moving_duration {
+                    (0, 0) => Player::Elephant,
+                    (0, _) => Player::Me,
+                    (_, 0) => Player::Elephant,
+                    (_, _) => panic!("invalid case")
+                }
+            },
+            Player::Elephant => {
+                let duration = self.distance_matrix[elephant_pos][target];
+                flow += (time_left-duration) * self.flow_rates[target];
+                position = (cur_pos, target);
+                if duration < time_wait {
+                    moving_duration = (time_wait-duration, 0);
+                    time_left -=  duration;
+                } else {
+                    moving_duration = (0, duration-time_wait);
+                    time_left -= time_wait;
+                 
+                }
+                match moving_duration {
+                    (0, 0) => Player::Me,
+                    (0, _) => Player::Me,
+                    (_, 0) => Player::Elephant,
+                    (_,_) => panic!("invalid case")
+                }
+            }  
+        };
+
+        State {
+            valve_states,
+            next_player,
+            moving_duration,
+            flow,
+            position,
+            time_left,
+            actions
+        }
+    }
+
+    fn legal_actions(&self, state: &State) -> Vec<Action> {
+        let mut actions = vec![];
+        let pos = match state.next_player {
+            Player::Me => state.position.0,
+            Player::Elephant => state.position.1
+        };
+        // As both players start alike, a plan in which I stop first is the
+        // same as one in which the elephant does, so only the elephant
+        // rests, and only while I still have a valve to open.
+        if state.next_player == Player::Elephant && state.moving_duration.0 < state.time_left {
+            let time_left = state.time_left - state.moving_duration.0;
+            if self.can_open_any(state, state.position.0, time_left) {
+                actions.push(Action::Rest);
+            }
+        }
+
+        for (valve, cost) in self.distance_matrix[pos].iter().enumerate() {
+            if self.can_open(state, valve, *cost, state.time_left) {
+                actions.push(Action::Open(valve));
+            }
+        }
+        actions
+    }
+
+    fn can_open(&self, state: &State, valve: usize, cost: usize, time_left: usize) -> bool {
+        valve != state.position.0 && valve != state.position.1 && cost <= time_left && !state.valve_states[valve] && self.flow_rates[valve] > 0
+    }
+
+    fn can_open_any(&self, state: &State, pos: usize, time_left: usize) -> bool {
+        self.distance_matrix[pos].iter()
+        .enumerate()
+        .any(|(valve, &cost)| self.can_open(state, valve, cost, time_left))
+    }
+    
+    /// An upper bound of the pressure still to be released, opening each
+    /// closed valve as early as the nearest player could reach it. A player
+    /// who rests reaches none.
+    fn heuristic(&self, state: &State) -> i32 {
+        let players = [
+            (state.position.0, state.moving_duration.0),
+            (state.position.1, state.moving_duration.1)
+        ];
+        let mut flow: i32 = 0;
+        let closed_valves: Vec<usize> = (0..self.valves.len()).filter(|&v| !state.valve_states[v]).collect();
+        for valve in closed_valves {
+            let time_left = players.iter()
+            .filter(|&&(_, wait)| wait < state.time_left)
+            .map(|&(pos, wait)| (state.time_left - wait).saturating_sub(self.distance_matrix[pos][valve]))
+            .max()
+            .unwrap_or(0);
+            flow += (time_left * self.flow_rates[valve]) as i32;
+        }
+        flow
+    }
+
+
+    fn compute_plan(&self, initial_valve: &str, time_left: usize) -> State {
+        let current_valve = Self::get_index(&self.valves, initial_valve).unwrap();
+        let moving_duration = if self.is_two_player {
+            (0, 0)
+        } else {
+            (0, usize::MAX)
+        };
+
+        let initial = State {
+            time_left,
+            position: (current_valve, current_valve),
+            valve_states: vec![false; self.valves.len()],
+            actions: Vec::new(),
+            flow: 0,
+            moving_duration,
+            next_player: Player::Me
+        };
+
+        let mut best_state = initial.clone();
+        let mut queue = PriorityQueue::new();
+        queue.push(initial, i32::MIN);
+       
+        while let Some((current_state, _)) = queue.pop() {
+            
+            for action in self.legal_actions(&current_state) {
+                let next_state = self.expand(&current_state, action);
+                let priority = (next_state.flow as i32) + self.heuristic(&next_state);
+                if priority >= best_state.flow  as i32 {
+                    queue.push(next_state, priority);
+                }
+            }
+
+            //println!("best={}, Queue: {}", best_state.flow, queue.len());
+            if current_state.flow > best_state.flow {
+                best_state = current_state.clone();
+            }
+        }
+        
+        best_state
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = ValveSystem;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<ValveSystem, ParseError> {
+        ValveSystem::parse(input, false)
+    }
+
+    fn part1(system: &ValveSystem) -> usize {
+        let state = system.compute_plan("AA", 30);
+        state.flow
+    }
+
+    fn part2(system: &ValveSystem) -> usize {
+        let system = ValveSystem { is_two_player: true, ..system.clone() };
+        let state = system.compute_plan("AA", 26);
+        state.flow
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part1(&input), 1651);
+        assert_eq!(Day16::part2(&input), 1707);
+    }
+}
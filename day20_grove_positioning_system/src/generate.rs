@@ -0,0 +1,53 @@
+use aoc_core::random::{Rng, StdRng};
+
+/// `size + 1` numbers with exactly one zero. The others are taken from a
+/// range not much wider than the list, so there are plenty of duplicates,
+/// and half of them are negative.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let bound = 3 * size as i64;
+    let mut numbers: Vec<i64> = (0..size)
+    .map(|_| {
+        let n = rng.gen_range(1..=bound);
+        if rng.gen_bool(0.5) { -n } else { n }
+    })
+    .collect();
+    numbers.insert(rng.gen_range(0..=size), 0);
+    numbers.iter().map(|n| format!("{}\n", n)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day20;
+
+    /// Mixes by swapping each number with its right neighbour as often as
+    /// it has to move. Moving by a full round past the other numbers
+    /// changes nothing, so only the remainder is walked.
+    fn reference(input: &str, rounds: usize, key: i64) -> i64 {
+        let numbers: Vec<i64> = input.lines().map(|n| n.parse::<i64>().unwrap() * key).collect();
+        let n = numbers.len();
+        let mut ring: Vec<usize> = (0..n).collect();
+        for _ in 0..rounds {
+            for (i, &value) in numbers.iter().enumerate() {
+                let mut position = ring.iter().position(|&j| j == i).unwrap();
+                for _ in 0..value.rem_euclid(n as i64 - 1) {
+                    ring.swap(position, (position + 1) % n);
+                    position = (position + 1) % n;
+                }
+            }
+        }
+        let zero = ring.iter().position(|&j| numbers[j] == 0).unwrap();
+        [1000, 2000, 3000].iter().map(|offset| numbers[ring[(zero + offset) % n]]).sum()
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 30, |input| {
+            let numbers = Day20::parse(input).unwrap();
+            assert_eq!(Day20::part1(&numbers), reference(input, 1, 1));
+            assert_eq!(Day20::part2(&numbers), reference(input, 10, 811589153));
+        });
+    }
+}
@@ -0,0 +1,76 @@
+pub mod generate;
+
+use aoc_core::{parse::number, ParseError, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers: Vec<i64> = input.lines().map(|n| number(n.trim())).collect::<Result<_, _>>()?;
+    if numbers.len() < 2 {
+        return Err(ParseError::at(input, "expected at least two numbers"));
+    }
+    if numbers.iter().filter(|&&n| n == 0).count() != 1 {
+        return Err(ParseError::at(input, "expected exactly one 0"));
+    }
+    Ok(numbers)
+}
+
+fn solve(numbers: &[i64], n_times: usize, decryption_key: i64) -> Vec<i64> {
+    let mut positions: Vec<usize> = (0..numbers.len()).collect();
+    let numbers: Vec<i64> = numbers.iter().map(|&n| n * decryption_key).collect();
+    for _ in 0..n_times {
+        for (i, &val) in numbers.iter().enumerate() {
+            let cur_pos = positions.iter().position(|&j| j == i).unwrap();
+            let mut new_pos = (cur_pos as i64 + val) % (positions.len() as i64 - 1);
+            if new_pos < 0 {
+                new_pos += positions.len() as i64 - 1;
+            }
+            positions.remove(cur_pos);
+            positions.insert(new_pos as usize, i);
+        }
+    }
+    positions.iter().map(|&i| numbers[i]).collect()
+}
+
+fn compute_grove_coordinates(mixed: &[i64]) -> i64 {
+    let zero_index = mixed.iter().position(|&i| i == 0).unwrap();
+    mixed[(zero_index + 1000) % mixed.len()]
+        + mixed[(zero_index + 2000) % mixed.len()]
+        + mixed[(zero_index + 3000) % mixed.len()]
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> i64 {
+        let decrypted = solve(input, 1, 1);
+        compute_grove_coordinates(&decrypted)
+    }
+
+    fn part2(input: &Vec<i64>) -> i64 {
+        let decrypted = solve(input, 10, 811589153);
+        compute_grove_coordinates(&decrypted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(Day20::part1(&input), 3);
+        assert_eq!(Day20::part2(&input), 1623178306);
+    }
+}
@@ -0,0 +1,102 @@
+use aoc_core::random::{Rng, SliceRandom, StdRng};
+
+enum Job {
+    Number(i64),
+    Operation(usize, char, usize)
+}
+
+/// Adds a monkey yelling `value`, waiting for up to `depth` levels of other
+/// monkeys, and returns its index. Operands are picked so that every
+/// division is exact.
+fn add(rng: &mut StdRng, jobs: &mut Vec<Job>, value: i64, depth: usize) -> usize {
+    let divisors: Vec<i64> = (2..=value.min(20)).filter(|d| value % d == 0).collect();
+    let operation = if depth == 0 || rng.gen_bool(0.2) { None } else { Some(rng.gen_range(0..4)) };
+    let operands = match operation {
+        Some(0) if value > 1 => {
+            let a = rng.gen_range(1..value);
+            Some((a, '+', value - a))
+        },
+        Some(1) => {
+            let b = rng.gen_range(1..=20);
+            Some((value + b, '-', b))
+        },
+        Some(2) if !divisors.is_empty() => {
+            let b = *divisors.choose(rng).unwrap();
+            Some((value / b, '*', b))
+        },
+        Some(3) => {
+            let b = rng.gen_range(2..=5);
+            Some((value * b, '/', b))
+        },
+        _ => None
+    };
+    let job = match operands {
+        Some((a, op, b)) => Job::Operation(add(rng, jobs, a, depth - 1), op, add(rng, jobs, b, depth - 1)),
+        None => Job::Number(value)
+    };
+    jobs.push(job);
+    jobs.len() - 1
+}
+
+/// A tree of monkeys up to `size` levels deep, where both monkeys `root`
+/// waits for yell the same number. One of the monkeys yelling a number is
+/// the human, so that number is the answer to the second part.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let value = rng.gen_range(1..=1000);
+    let mut jobs = Vec::new();
+    let a = add(rng, &mut jobs, value, size);
+    let b = add(rng, &mut jobs, value, size);
+    let leaves: Vec<usize> = (0..jobs.len()).filter(|&i| matches!(jobs[i], Job::Number(_))).collect();
+    let human = *leaves.choose(rng).unwrap();
+    let mut names: Vec<String> = Vec::new();
+    while names.len() < jobs.len() {
+        let name: String = (0..4).map(|_| rng.gen_range('a'..='z')).collect();
+        if name != "root" && name != "humn" && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names[human] = "humn".to_string();
+    let mut lines: Vec<String> = jobs.iter().enumerate()
+    .map(|(i, job)| match job {
+        Job::Number(n) => format!("{}: {}\n", names[i], n),
+        Job::Operation(a, op, b) => format!("{}: {} {} {}\n", names[i], names[*a], op, names[*b])
+    })
+    .collect();
+    lines.push(format!("root: {} + {}\n", names[a], names[b]));
+    lines.shuffle(rng);
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use aoc_core::{random::check, Solution};
+
+    use super::*;
+    use crate::Day21;
+
+    fn yell(jobs: &HashMap<&str, &str>, name: &str) -> i64 {
+        match jobs[name].split(' ').collect::<Vec<_>>()[..] {
+            [a, "+", b] => yell(jobs, a) + yell(jobs, b),
+            [a, "-", b] => yell(jobs, a) - yell(jobs, b),
+            [a, "*", b] => yell(jobs, a) * yell(jobs, b),
+            [a, "/", b] => yell(jobs, a) / yell(jobs, b),
+            [n] => n.parse().unwrap(),
+            _ => unreachable!()
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        check(generate, 100, 8, |input| {
+            let operations = Day21::parse(input).unwrap();
+            let mut jobs: HashMap<&str, &str> = input.lines().map(|l| l.split_once(": ").unwrap()).collect();
+            assert_eq!(Day21::part1(&operations), yell(&jobs, "root"));
+            let human = Day21::part2(&operations).to_string();
+            jobs.insert("humn", &human);
+            let (a, b) = jobs["root"].split_once(" + ").unwrap();
+            assert_eq!(yell(&jobs, a), yell(&jobs, b));
+        });
+    }
+}
@@ -0,0 +1,185 @@
+pub mod generate;
+
+use std::collections::HashMap;
+
+use aoc_core::{parse::{number, split_once}, ParseError, Solution};
+
+#[derive(Clone, Debug)]
+enum Expr {
+    Constant(String, i64),
+    Sum(String, Box<Expr>, Box<Expr>),
+    Sub(String, Box<Expr>, Box<Expr>),
+    Mul(String, Box<Expr>, Box<Expr>),
+    Div(String, Box<Expr>, Box<Expr>)
+}
+
+impl Expr {
+    fn eval(&self) -> i64 {
+        match self {
+            Self::Constant(_, n) => *n,
+            Self::Sum(_, a, b) => a.eval() + b.eval(),
+            Self::Sub(_, a, b) => a.eval() - b.eval(),
+            Self::Mul(_, a, b) => a.eval() * b.eval(),
+            Self::Div(_, a, b) => a.eval() / b.eval()
+        }
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        match self {
+            Self::Constant(n, _) => n == name,
+            Self::Sum(n, a, b) | Self::Sub(n, a, b) |Self::Mul(n, a, b) | Self::Div(n, a, b) => {
+                n == name || a.contains(name) || b.contains(name)
+            }
+        }
+    }
+}
+
+fn build_ast(name: &str, operations: &HashMap<String, String>) -> Expr {
+    let expr = &operations[name];
+    if expr.trim().contains(' ') {
+        let items: Vec<&str> = expr.trim().split(' ').collect();
+        let a = Box::new(build_ast(items[0], operations));
+        let b = Box::new(build_ast(items[2], operations));
+        match items[1].trim() {
+            "+" => Expr::Sum(name.to_string(), a, b),
+            "-" => Expr::Sub(name.to_string(), a, b),
+            "*" => Expr::Mul(name.to_string(), a, b),
+            "/" => Expr::Div(name.to_string(), a, b),
+            _ => unreachable!("operations are validated while parsing"),
+        }
+    } else {
+        Expr::Constant(name.to_string(), expr.trim().parse().unwrap())
+    }
+}
+
+fn operands(expr: &str) -> Option<(&str, &str)> {
+    let items: Vec<&str> = expr.split_whitespace().collect();
+    match items[..] {
+        [a, _, b] => Some((a, b)),
+        _ => None
+    }
+}
+
+fn validate(jobs: &[(&str, &str)]) -> Result<(), ParseError> {
+    let names: HashMap<&str, &str> = jobs.iter().copied().collect();
+    for (_, expr) in jobs {
+        let items: Vec<&str> = expr.split_whitespace().collect();
+        match items[..] {
+            [n] => {
+                number::<i64>(n)?;
+            },
+            [a, op, b] => {
+                if !["+", "-", "*", "/"].contains(&op) {
+                    return Err(ParseError::at(op, "invalid operation, expected one of +, -, *, /"));
+                }
+                for monkey in [a, b] {
+                    if !names.contains_key(monkey) {
+                        return Err(ParseError::at(monkey, "unknown monkey"));
+                    }
+                }
+            },
+            _ => return Err(ParseError::at(expr, "expected a number or an operation like `abcd + efgh`"))
+        }
+    }
+    for monkey in ["root", "humn"] {
+        if !names.contains_key(monkey) {
+            return Err(ParseError::at(jobs.last().map(|j| j.1).unwrap_or(""), format!("expected a monkey named `{}`", monkey)));
+        }
+    }
+    if operands(names["root"]).is_none() {
+        return Err(ParseError::at(names["root"], "`root` has to compare two monkeys"));
+    }
+    Ok(())
+}
+
+fn parse_operations(input: &str) -> Result<HashMap<String, String>, ParseError> {
+    let jobs: Vec<(&str, &str)> = input
+        .lines()
+        .map(|l| split_once(l, ": "))
+        .collect::<Result<_, _>>()?;
+    validate(&jobs)?;
+    Ok(jobs.iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect())
+}
+
+fn solve_for(name: &str, lhs: &Expr, rhs: &Expr) -> Expr {
+    let (lhs, rhs) = match lhs {
+        Expr::Constant(_, _) => {
+            return rhs.clone();
+        },
+        Expr::Sum(e, a, b) => {
+            if a.contains(name) {
+                (a.as_ref().clone(), Expr::Sub(format!("inv_{}", e), Box::new(rhs.clone()), b.clone()))
+            } else {
+                (b.as_ref().clone(), Expr::Sub(format!("inv_{}", e), Box::new(rhs.clone()), a.clone()))
+            }
+        },
+        Expr::Sub(e, a, b) => {
+            if a.contains(name) {
+                (a.as_ref().clone(), Expr::Sum(format!("inv_{}", e), Box::new(rhs.clone()), b.clone()))
+            } else {
+                (b.as_ref().clone(), Expr::Sub(format!("inv_{}", e), a.clone(), Box::new(rhs.clone())))
+            }
+        },
+        Expr::Mul(e, a, b) => {
+            if a.contains(name) {
+                (a.as_ref().clone(), Expr::Div(format!("inv_{}", e), Box::new(rhs.clone()), b.clone()))
+            } else {
+                (b.as_ref().clone(), Expr::Div(format!("inv_{}", e), Box::new(rhs.clone()),  a.clone()))
+            }
+        },
+        Expr::Div(e, a, b) => {
+            if a.contains(name) {
+                (a.as_ref().clone(), Expr::Mul(format!("inv_{}", e), Box::new(rhs.clone()), b.clone()))
+            } else {
+                (b.as_ref().clone(), Expr::Div(format!("inv_{}", e), a.clone(), Box::new(rhs.clone())))
+            }
+        }
+    };
+    solve_for(name, &lhs, &rhs)   
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = HashMap<String, String>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<HashMap<String, String>, ParseError> {
+        parse_operations(input)
+    }
+
+    fn part1(operations: &HashMap<String, String>) -> i64 {
+        let ast = build_ast("root", operations);
+        ast.eval()
+    }
+
+    fn part2(operations: &HashMap<String, String>) -> i64 {
+        let (mut a, mut b) = operands(&operations["root"]).unwrap();
+        if !build_ast(a, operations).contains("humn") {
+            (a, b) = (b, a);
+        }
+        let lhs = build_ast(a, operations);
+        let rhs = build_ast(b, operations);
+        let solution = solve_for("humn", &lhs, &rhs);
+        solution.eval()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/task_0.txt");
+
+    #[test]
+    fn example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part1(&input), 152);
+        assert_eq!(Day21::part2(&input), 301);
+    }
+}